@@ -0,0 +1,64 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Collects every solution in `src/yYYYY/NN.rs` into a registry that `src/solutions.rs` includes.
+ */
+use std::{env, fs, path::Path};
+
+/// Lists `(number, path)` of the entries in `dir` named `<prefix><number><suffix>`.
+fn numbered_entries<T: std::str::FromStr + Ord>(
+    dir: &Path,
+    prefix: &str,
+    suffix: &str,
+) -> Vec<(T, String)> {
+    let mut entries: Vec<(T, String)> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse::<T>()
+                .ok()?;
+            Some((number, path.to_string_lossy().to_string()))
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_dir = Path::new(&manifest_dir).join("src");
+    // also picks up year folders that are added later.
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, year_dir) in numbered_entries::<u16>(&src_dir, "y", "") {
+        modules += &format!("pub mod y{} {{\n", year);
+
+        for (day, path) in numbered_entries::<u8>(Path::new(&year_dir), "", ".rs") {
+            modules += &format!("    #[path = {:?}]\n    pub mod day{:02};\n", path, day);
+            entries += &format!(
+                "    advent_of_code::solution!({}, {}, y{}::day{:02}),\n",
+                year, day, year, day
+            );
+        }
+
+        modules += "}\n";
+    }
+
+    let registry = format!(
+        "{}\n/// Every solution in `src/yYYYY/NN.rs`, ordered by year and day.\npub const SOLUTIONS: &[advent_of_code::Solution] = &[\n{}];\n",
+        modules, entries
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
@@ -0,0 +1,82 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::Client;
+use advent_of_code::puzzle;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let day = args.free_from_str()?;
+    advent_of_code::finish_args(args)?;
+    Ok(Args {
+        day,
+        year: year.unwrap_or_else(advent_of_code::default_year),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = args.year;
+    let puzzle_path = puzzle::get_path(year, args.day);
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("Fetching puzzle for day {}, {}...", args.day, year);
+
+    let html = match client.get_puzzle(year, args.day) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Failed to fetch puzzle: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let num_parts = puzzle::extract_articles(&html).len();
+    if num_parts == 0 {
+        eprintln!("Could not find a puzzle description on the page.");
+        process::exit(1);
+    }
+
+    match puzzle::write_markdown(&puzzle_path, &html, client.base_url()) {
+        Ok(_) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote {} to \"{}\".",
+                if num_parts == 1 {
+                    "part one"
+                } else {
+                    "both parts"
+                },
+                puzzle_path.display()
+            );
+            if num_parts == 1 {
+                println!("Run this again after solving part one to add part two.");
+            }
+        }
+        Err(e) => {
+            eprintln!("could not write puzzle file: {}", e);
+            process::exit(1);
+        }
+    }
+}
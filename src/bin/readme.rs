@@ -0,0 +1,110 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::limits::{self, LimitedAllocator, Limits};
+use advent_of_code::{config, readme, ReadError, Timing};
+use std::sync::Arc;
+use std::{fs, process};
+
+#[path = "../solutions.rs"]
+mod solutions;
+
+#[global_allocator]
+static ALLOCATOR: LimitedAllocator = LimitedAllocator;
+
+struct Args {
+    year: u16,
+    iterations: u32,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let iterations = args.opt_value_from_str("--iterations")?;
+    advent_of_code::finish_args(args)?;
+    Ok(Args {
+        year: year.unwrap_or_else(advent_of_code::default_year),
+        iterations: iterations.unwrap_or(config::get().bench_iterations),
+    })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let readme_path = advent_of_code::project_dir().join("README.md");
+    let readme = match advent_of_code::read_path(&readme_path) {
+        Ok(readme) => readme,
+        Err(e) => exit_with_error(&format!("Failed to read README: {}", e)),
+    };
+
+    let config = config::get();
+    let limits = Limits {
+        timeout: config.timeout,
+        memory: config.memory_limit,
+    };
+    let mut results = vec![];
+
+    // days without an input are left out, like in `cargo all`.
+    for day in 1..=25 {
+        let Some(solution) = solutions::find_solution(args.year, day) else {
+            continue;
+        };
+
+        let input = match advent_of_code::read_file("inputs", args.year, day) {
+            Ok(input) => Arc::from(input),
+            Err(ReadError::NotFound(_)) => continue,
+            Err(e) => exit_with_error(&format!("Failed to read input of day {}: {}", day, e)),
+        };
+
+        println!("Benchmarking day {}...", day);
+        let answers = advent_of_code::read_answers(args.year, day);
+        for part in [1, 2] {
+            let mut result = solution.run(part, &input, Timing::Bench(args.iterations), limits);
+            result.check(&answers);
+            results.push(result);
+        }
+    }
+
+    for (result, failure) in results
+        .iter()
+        .filter_map(|r| Some((r, r.failure.as_ref()?)))
+    {
+        eprintln!(
+            "Day {:02} Part {} failed: {}",
+            result.day, result.part, failure
+        );
+    }
+    if limits::stopped_still_running() > 0 {
+        eprintln!("Some parts were stopped but are still running, so later timings may be skewed.");
+    }
+
+    let table = readme::render_table(args.year, &results);
+    let readme = match readme::replace_section(&readme, &table) {
+        Ok(readme) => readme,
+        Err(e) => exit_with_error(&format!(
+            "Failed to update README: {}. Add both markers where the table should go.",
+            e
+        )),
+    };
+
+    match fs::write(&readme_path, readme) {
+        Ok(_) => {
+            println!("---");
+            println!(
+                "🎄 Updated the table in \"{}\", {} ⭐ so far.",
+                readme_path.display(),
+                results.iter().filter(|r| readme::has_star(r)).count()
+            );
+        }
+        Err(e) => exit_with_error(&format!("could not write README: {}", e)),
+    }
+}
@@ -2,61 +2,103 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::client::Client;
+use advent_of_code::puzzle;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<PART_ONE_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<PART_TWO_TYPE> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = advent_of_code::read_file("examples", YEAR, DAY).unwrap();
+        assert_eq!(part_one(&input), PART_ONE_ANSWER);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", YEAR, DAY).unwrap();
+        assert_eq!(part_two(&input), PART_TWO_ANSWER);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let day = args.free_from_str()?;
+    advent_of_code::finish_args(args)?;
+    Ok(Args {
+        day,
+        year: year.unwrap_or_else(advent_of_code::default_year),
+    })
+}
+
+fn fetch_puzzle(client: &Client, year: u16, day: u8) -> Option<String> {
+    match client.get_puzzle(year, day) {
+        Ok(html) => Some(html),
+        Err(e) => {
+            eprintln!(
+                "Failed to fetch puzzle, scaffolding without its example: {}",
+                e
+            );
+            None
+        }
+    }
+}
+
+/// The return type of a part and the expected value of its test, e.g. `u64` and
+/// `Some(4294967296)`. Answers that are not integers, like the crates of 2022's day 5, are strings.
+fn expected_answer(answer: &Option<String>) -> (&'static str, String) {
+    let Some(answer) = answer else {
+        return ("u32", "None".to_string());
+    };
+    match answer.parse::<i128>() {
+        Ok(n) if u32::try_from(n).is_ok() => ("u32", format!("Some({})", n)),
+        Ok(n) if u64::try_from(n).is_ok() => ("u64", format!("Some({})", n)),
+        Ok(n) if i64::try_from(n).is_ok() => ("i64", format!("Some({})", n)),
+        _ => ("String", format!("Some({:?}.to_string())", answer)),
+    }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    fs::create_dir_all(path.parent().unwrap())?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
+    let (day, year) = match parse_args() {
+        Ok(args) => (args.day, args.year),
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
@@ -64,9 +106,11 @@ fn main() {
 
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let example_path = advent_of_code::get_path("examples", year, day);
+    let module_path = advent_of_code::project_dir()
+        .join("src")
+        .join(format!("y{}", year))
+        .join(format!("{}.rs", day_padded));
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +120,28 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    // fetched once the module is created, so scaffolding an existing day makes no request. The
+    // puzzle is only fetched if a session cookie is set up, scaffolding works without it.
+    let client = Client::from_env().ok();
+    let html = client.as_ref().and_then(|c| fetch_puzzle(c, year, day));
+    let example = html.as_deref().and_then(puzzle::extract_example);
+    let [part_one, part_two] = html
+        .as_deref()
+        .map(puzzle::extract_example_answers)
+        .unwrap_or_default()
+        .map(|answer| expected_answer(&answer));
+
+    let module = MODULE_TEMPLATE
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+        .replace("PART_ONE_TYPE", part_one.0)
+        .replace("PART_TWO_TYPE", part_two.0)
+        .replace("PART_ONE_ANSWER", &part_one.1)
+        .replace("PART_TWO_ANSWER", &part_two.1);
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,29 +149,45 @@ fn main() {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
+    match create_file(&example_path) {
+        Ok(mut file) => match &example {
+            // never overwrite an example that is already filled in.
+            _ if file.metadata().map(|m| m.len() > 0).unwrap_or(true) => {
+                println!("Kept existing example file \"{}\"", example_path.display())
+            }
+            Some(example) => match file.write_all(example.as_bytes()) {
+                Ok(_) => println!("Created example file \"{}\"", example_path.display()),
+                Err(e) => {
+                    eprintln!("Failed to write example file: {}", e);
+                    process::exit(1);
+                }
+            },
+            None => println!("Created empty example file \"{}\"", example_path.display()),
+        },
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to create example file: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+    if let (Some(client), Some(html)) = (&client, &html) {
+        let puzzle_path = puzzle::get_path(year, day);
+        match puzzle::write_markdown(&puzzle_path, html, client.base_url()) {
+            Ok(_) => println!("Created puzzle description \"{}\"", puzzle_path.display()),
+            Err(e) => eprintln!("Failed to write puzzle description: {}", e),
         }
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if year == advent_of_code::default_year() {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            &day_padded, year
+        );
+    }
 }
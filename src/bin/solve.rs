@@ -0,0 +1,98 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::limits::LimitedAllocator;
+use advent_of_code::{InputSource, Options};
+use std::process;
+
+#[path = "../solutions.rs"]
+mod solutions;
+
+#[global_allocator]
+static ALLOCATOR: LimitedAllocator = LimitedAllocator;
+
+struct Args {
+    day: u8,
+    /// Replaces the day's input file, e.g. with an input shared by someone else.
+    input: Option<InputSource>,
+    /// Shows the day's visualisation instead of solving it.
+    visualize: bool,
+    options: Options,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // options first: the day is whatever free argument is left.
+    let options = Options::parse(&mut args)?;
+    let input = args.opt_value_from_str("--input")?;
+    let visualize = args.contains("--visualize");
+    let day = args.free_from_str()?;
+    advent_of_code::finish_args(args)?;
+    Ok(Args {
+        day,
+        input,
+        visualize,
+        options,
+    })
+}
+
+fn main() {
+    let Args {
+        day,
+        input,
+        visualize,
+        options,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("Need to specify a day (as integer). example: `cargo solve 7`");
+            process::exit(1);
+        }
+    };
+
+    let solution = match solutions::find_solution(options.year, day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!(
+                "No solution for day {} of {}. Create one with `cargo scaffold {} --year {}`.",
+                day, options.year, day, options.year
+            );
+            process::exit(1);
+        }
+    };
+
+    // answers are only recorded for the day's own input, so other inputs are not checked.
+    let (input, answers) = match &input {
+        Some(source) => (source.read(), [None, None]),
+        None => (
+            advent_of_code::read_file("inputs", options.year, day),
+            advent_of_code::read_answers(options.year, day),
+        ),
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if visualize {
+        match solution.visualize {
+            Some(visualize) => visualize(&input),
+            None => {
+                eprintln!(
+                    "Day {} of {} has no visualisation. Register one in `src/solutions.rs`.",
+                    day, options.year
+                );
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    advent_of_code::solve_day(solution, &input, &answers, options);
+}
@@ -0,0 +1,147 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{Client, Submission};
+use advent_of_code::limits::{LimitedAllocator, Limits};
+use advent_of_code::{config, puzzle, Format, Timing, Verdict};
+use std::process;
+use std::sync::Arc;
+
+#[path = "../solutions.rs"]
+mod solutions;
+
+#[global_allocator]
+static ALLOCATOR: LimitedAllocator = LimitedAllocator;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let (day, part) = (args.free_from_str()?, args.free_from_str()?);
+    advent_of_code::finish_args(args)?;
+    Ok(Args {
+        day,
+        part,
+        year: year.unwrap_or_else(advent_of_code::default_year),
+    })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        Ok(_) => exit_with_error("Part must be 1 or 2. example: `cargo submit 7 1`"),
+        Err(e) => exit_with_error(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let year = args.year;
+
+    let solution = match solutions::find_solution(year, args.day) {
+        Some(solution) => solution,
+        None => exit_with_error(&format!("No solution for day {} of {}.", args.day, year)),
+    };
+
+    let input = match advent_of_code::read_file("inputs", year, args.day) {
+        Ok(input) => Arc::from(input),
+        Err(e) => exit_with_error(&format!("Failed to read input: {}", e)),
+    };
+    let config = config::get();
+    let limits = Limits {
+        timeout: config.timeout,
+        memory: config.memory_limit,
+    };
+
+    let mut result = solution.run(args.part, &input, Timing::Once, limits);
+    result.check(&advent_of_code::read_answers(year, args.day));
+    advent_of_code::print_result(&result, Format::Text);
+
+    let answer = match &result.answer {
+        Some(answer) => answer,
+        None => exit_with_error("Nothing to submit."),
+    };
+
+    match &result.verdict {
+        Verdict::Correct => {
+            println!("---");
+            println!("🎄 This answer was already accepted.");
+            return;
+        }
+        // submitting a known wrong answer would only count against the rate limit.
+        Verdict::Wrong { expected } => exit_with_error(&format!(
+            "Not submitting, \"{}\" was already accepted for this part.",
+            expected
+        )),
+        Verdict::Unknown => {}
+    }
+
+    if answer.contains('\n') {
+        exit_with_error("Multi-line answers can't be submitted. Read the letters off the output and submit them on the website.");
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => exit_with_error(&e.to_string()),
+    };
+
+    println!("---");
+    println!(
+        "Submitting \"{}\" for day {}, part {} ({})...",
+        answer, args.day, args.part, year
+    );
+
+    let submission = match client.post_answer(year, args.day, args.part, answer) {
+        Ok(submission) => submission,
+        Err(e) => exit_with_error(&format!("Failed to submit answer: {}", e)),
+    };
+
+    match submission {
+        Submission::Correct => {
+            println!("🎄 That's the right answer!");
+            match advent_of_code::write_answer(year, args.day, args.part, answer) {
+                Ok(_) => println!(
+                    "Recorded answer in \"{}\".",
+                    advent_of_code::get_path("answers", year, args.day).display()
+                ),
+                Err(e) => eprintln!("could not record answer: {}", e),
+            }
+
+            if args.part == 1 {
+                // part two unlocks now: refresh the puzzle description to include it.
+                let puzzle_path = puzzle::get_path(year, args.day);
+                match client.get_puzzle(year, args.day) {
+                    Ok(html) => {
+                        match puzzle::write_markdown(&puzzle_path, &html, client.base_url()) {
+                            Ok(_) => println!(
+                                "Updated puzzle description \"{}\".",
+                                puzzle_path.display()
+                            ),
+                            Err(e) => eprintln!("could not write puzzle file: {}", e),
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to fetch puzzle: {}", e),
+                }
+            }
+        }
+        Submission::TooHigh => exit_with_error("That's not the right answer, it is too high."),
+        Submission::TooLow => exit_with_error("That's not the right answer, it is too low."),
+        Submission::Wrong => exit_with_error("That's not the right answer."),
+        Submission::RateLimited(wait) => exit_with_error(&format!(
+            "You gave an answer too recently. Try again in {}s.",
+            wait.as_secs()
+        )),
+        Submission::WrongLevel => {
+            exit_with_error("This part is already solved or not unlocked yet.")
+        }
+        Submission::Unknown(text) => exit_with_error(&format!("Unexpected response: {}", text)),
+    }
+}
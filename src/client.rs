@@ -0,0 +1,341 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{config, puzzle};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the advent of code website, authenticated with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor the session file (`session_file` in `aoc.toml`) is set.
+    MissingSession,
+    /// The server answered with an error status, e.g. 404 for a puzzle that is not unlocked yet.
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to \"{}\"",
+                config::get().session_file.display()
+            ),
+            ClientError::Status(status, body) => {
+                write!(f, "server responded with {}: {}", status, body.trim())
+            }
+            ClientError::Transport(e) => write!(f, "request failed: {}", e),
+            ClientError::Io(e) => write!(f, "could not read response: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(e) => ClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, retry after the given wait.
+    RateLimited(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that could not be recognized, with its text.
+    Unknown(String),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("advent_of_code/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    /// Creates a client for `AOC_BASE_URL` (default: adventofcode.com), with the session cookie
+    /// from `AOC_SESSION` or the session file set in `aoc.toml`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = read_session().ok_or(ClientError::MissingSession)?;
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn get_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(parse_submission(&response.into_string()?))
+    }
+
+    /// Fetches the HTML of a puzzle page. Part two is only included once part one is solved.
+    pub fn get_puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+}
+
+pub fn parse_submission(html: &str) -> Submission {
+    let text = match (html.find("<article>"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start + "<article>".len()..end],
+        _ => html,
+    };
+
+    if text.contains("That's the right answer") {
+        Submission::Correct
+    } else if text.contains("your answer is too high") {
+        Submission::TooHigh
+    } else if text.contains("your answer is too low") {
+        Submission::TooLow
+    } else if text.contains("That's not the right answer") {
+        Submission::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Submission::RateLimited(parse_wait(text).unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Submission::WrongLevel
+    } else {
+        Submission::Unknown(puzzle::strip_tags(text).trim().to_string())
+    }
+}
+
+/// Parses the wait in "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |wait, token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "s" => Some(wait + Duration::from_secs(value)),
+                "m" => Some(wait + Duration::from_secs(value * 60)),
+                "h" => Some(wait + Duration::from_secs(value * 3600)),
+                _ => None,
+            }
+        })
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session);
+        }
+    }
+
+    let session = fs::read_to_string(config::get().session_path()?).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// The year of the most recent event: the current year from December on, the previous one before.
+pub fn latest_event_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let (year, month, _) = civil_from_days((secs / 86400) as i64);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Converts days since 1970-01-01 to (year, month, day).
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn civil_from_days(days: i64) -> (u16, u8, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as u16, month as u8, day as u8)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single canned response on a local port, returning its base url and a handle
+    /// that yields the raw request head.
+    pub(crate) fn serve_once(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request += &line;
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_get_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(&base_url, "abc\n");
+        assert_eq!(client.get_input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_get_input_error_status() {
+        let (base_url, server) = serve_once("404 Not Found", "not unlocked yet");
+        let client = Client::new(&base_url, "abc");
+        match client.get_input(2022, 25) {
+            Err(ClientError::Status(404, body)) => assert_eq!(body, "not unlocked yet"),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        );
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(
+            client.post_answer(2022, 1, 2, "45000").unwrap(),
+            Submission::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.contains("application/x-www-form-urlencoded"));
+    }
+
+    #[test]
+    fn test_parse_submission() {
+        assert_eq!(
+            parse_submission("<article><p>That's not the right answer; your answer is too high.  If you're stuck...</p></article>"),
+            Submission::TooHigh
+        );
+        assert_eq!(
+            parse_submission(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            ),
+            Submission::TooLow
+        );
+        assert_eq!(
+            parse_submission(
+                "<article><p>That's not the right answer.  If you're stuck...</p></article>"
+            ),
+            Submission::Wrong
+        );
+        assert_eq!(
+            parse_submission("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>"),
+            Submission::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_submission("<article><p>You gave an answer too recently.  You have 32s left to wait.</p></article>"),
+            Submission::RateLimited(Duration::from_secs(32))
+        );
+        assert_eq!(
+            parse_submission("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Submission::WrongLevel
+        );
+        assert_eq!(
+            parse_submission("<article><p>Something <em>else</em>.</p></article>"),
+            Submission::Unknown("Something else.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19327), (2022, 12, 1));
+        assert_eq!(civil_from_days(19357), (2022, 12, 31));
+        assert_eq!(civil_from_days(19358), (2023, 1, 1));
+        assert_eq!(civil_from_days(19417), (2023, 3, 1));
+    }
+}
@@ -0,0 +1,378 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Reads the project settings from `aoc.toml`. Only the subset of TOML that the file needs is
+ * supported: `key = value` lines with number or string values, and `#` comments.
+ */
+use crate::{limits, Format, DEFAULT_BENCH_ITERATIONS};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// The project settings. Every key of `aoc.toml` is optional and falls back to these defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The year commands use when no `--year` is passed, the most recent event if unset.
+    pub year: Option<u16>,
+    pub inputs_dir: PathBuf,
+    pub examples_dir: PathBuf,
+    /// The file holding the session cookie, used when `AOC_SESSION` is not set.
+    pub session_file: PathBuf,
+    pub format: Format,
+    pub bench_iterations: u32,
+    /// The file that `--save` records timings in.
+    pub bench_history: PathBuf,
+    /// How much slower than its baseline a part may get before it is flagged, in percent.
+    pub regression_threshold: u32,
+    /// How long a part may run before it is stopped, unlimited if unset.
+    pub timeout: Option<Duration>,
+    /// How many bytes a part may allocate before it is stopped, unlimited if unset.
+    pub memory_limit: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            inputs_dir: PathBuf::from("src/inputs"),
+            examples_dir: PathBuf::from("src/examples"),
+            session_file: PathBuf::from("~/.adventofcode.session"),
+            format: Format::Text,
+            bench_iterations: DEFAULT_BENCH_ITERATIONS,
+            bench_history: PathBuf::from("bench_history.tsv"),
+            regression_threshold: 10,
+            timeout: None,
+            memory_limit: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    /// A line that could not be parsed, with its 1-based line number.
+    Parse {
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not read {}: {}", CONFIG_FILE, e),
+            ConfigError::Parse { line, message } => {
+                write!(f, "{}, line {}: {}", CONFIG_FILE, line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Reads `aoc.toml` in the project root. A missing file means all defaults.
+    pub fn load() -> Result<Self, ConfigError> {
+        match fs::read_to_string(crate::project_dir().join(CONFIG_FILE)) {
+            Ok(content) => Config::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(ConfigError::Io(e)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let mut config = Config::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let error = |message: String| ConfigError::Parse {
+                line: i + 1,
+                message,
+            };
+
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`".to_string()))?;
+            let (key, value) = (key.trim(), Value::parse(value.trim()).map_err(error)?);
+
+            match key {
+                "year" => config.year = Some(value.integer(key).map_err(error)?),
+                "inputs_dir" => config.inputs_dir = value.string(key).map_err(error)?.into(),
+                "examples_dir" => config.examples_dir = value.string(key).map_err(error)?.into(),
+                "session_file" => config.session_file = value.string(key).map_err(error)?.into(),
+                "format" => {
+                    config.format = value.string(key).map_err(error)?.parse().map_err(error)?
+                }
+                "bench_iterations" => {
+                    config.bench_iterations = value.integer(key).map_err(error)?
+                }
+                "bench_history" => config.bench_history = value.string(key).map_err(error)?.into(),
+                "regression_threshold" => {
+                    config.regression_threshold = value.integer(key).map_err(error)?
+                }
+                "timeout" => {
+                    let timeout = value.number_with(key, limits::parse_timeout);
+                    config.timeout = Some(timeout.map_err(error)?)
+                }
+                "memory_limit" => {
+                    let bytes = value.number_with(key, limits::parse_memory_limit);
+                    config.memory_limit = Some(bytes.map_err(error)?)
+                }
+                _ => return Err(error(format!("unknown key \"{}\"", key))),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The folder of a kind of day file: the configured inputs and examples folders,
+    /// `src/<folder>` for everything else.
+    pub fn folder(&self, folder: &str) -> PathBuf {
+        match folder {
+            "inputs" => self.inputs_dir.clone(),
+            "examples" => self.examples_dir.clone(),
+            _ => PathBuf::from("src").join(folder),
+        }
+    }
+
+    /// The session file with a leading `~` expanded to the home directory.
+    /// Relative paths are resolved against the project root.
+    pub fn session_path(&self) -> Option<PathBuf> {
+        match self.session_file.strip_prefix("~") {
+            Ok(rest) => {
+                let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+                Some(PathBuf::from(home).join(rest))
+            }
+            Err(_) => Some(crate::project_dir().join(&self.session_file)),
+        }
+    }
+}
+
+/// The settings of `aoc.toml`, read once per process.
+/// Exits with an error message if the file is invalid, like invalid arguments do.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to read config: {}", e);
+            std::process::exit(1);
+        }
+    })
+}
+
+enum Value {
+    /// The text of a number, parsed once the type of its key is known.
+    Number(String),
+    String(String),
+}
+
+impl Value {
+    fn parse(value: &str) -> Result<Self, String> {
+        if let Some(rest) = value.strip_prefix('"') {
+            let inner = rest
+                .strip_suffix('"')
+                .ok_or_else(|| "unterminated string".to_string())?;
+            return unescape(inner).map(Value::String);
+        }
+
+        let number = value.replace('_', "");
+        match number.parse::<f64>() {
+            Ok(_) => Ok(Value::Number(number)),
+            Err(_) => Err(format!(
+                "expected a number or a quoted string, found `{}`",
+                value
+            )),
+        }
+    }
+
+    fn integer<T: TryFrom<i64>>(self, key: &str) -> Result<T, String> {
+        let not_integer = || format!("\"{}\" must be an integer", key);
+        let n: i64 = match self {
+            Value::Number(number) => number.parse().map_err(|_| not_integer())?,
+            Value::String(_) => return Err(not_integer()),
+        };
+        T::try_from(n).map_err(|_| format!("\"{}\" is out of range: {}", key, n))
+    }
+
+    /// The number, checked by the parser of the matching command-line flag.
+    fn number_with<T>(
+        self,
+        key: &str,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let number = self.number(key)?;
+        parse(&number).map_err(|e| format!("\"{}\": {}, found {}", key, e, number))
+    }
+
+    fn number(self, key: &str) -> Result<String, String> {
+        match self {
+            Value::Number(number) => Ok(number),
+            Value::String(_) => Err(format!("\"{}\" must be a number", key)),
+        }
+    }
+
+    fn string(self, key: &str) -> Result<String, String> {
+        match self {
+            Value::String(s) => Ok(s),
+            Value::Number(_) => Err(format!("\"{}\" must be a quoted string", key)),
+        }
+    }
+}
+
+/// Removes a `#` comment, unless the `#` is inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            other => return Err(format!("unsupported escape `\\{}`", other.unwrap_or(' '))),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+# settings
+year = 2021
+inputs_dir = "inputs/#private" # comment
+session_file = "C:\\aoc\\session"
+format = "json"
+bench_iterations = 1_000
+regression_threshold = 5
+timeout = 2.5
+memory_limit = 1024
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(2021),
+                inputs_dir: PathBuf::from("inputs/#private"),
+                session_file: PathBuf::from("C:\\aoc\\session"),
+                format: Format::Json,
+                bench_iterations: 1000,
+                regression_threshold: 5,
+                timeout: Some(Duration::from_millis(2500)),
+                memory_limit: Some(1 << 30),
+                ..Config::default()
+            }
+        );
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let message = |content: &str| match Config::parse(content) {
+            Err(ConfigError::Parse { line, message }) => (line, message),
+            other => panic!("unexpected result: {:?}", other),
+        };
+
+        assert_eq!(message("\nyear"), (2, "expected `key = value`".to_string()));
+        assert_eq!(
+            message("year = \"2022\""),
+            (1, "\"year\" must be an integer".to_string())
+        );
+        assert_eq!(
+            message("year = 99999"),
+            (1, "\"year\" is out of range: 99999".to_string())
+        );
+        assert_eq!(
+            message("year = 2022.5"),
+            (1, "\"year\" must be an integer".to_string())
+        );
+        assert_eq!(
+            message("timeout = 0"),
+            (
+                1,
+                "\"timeout\": expected a positive number of seconds, found 0".to_string()
+            )
+        );
+        assert_eq!(
+            message("timeout = \"5\""),
+            (1, "\"timeout\" must be a number".to_string())
+        );
+        assert_eq!(
+            message("memory_limit = 0"),
+            (
+                1,
+                "\"memory_limit\": expected a positive whole number of MiB, found 0".to_string()
+            )
+        );
+        assert_eq!(
+            message("memory_limit = 0.5"),
+            (
+                1,
+                "\"memory_limit\": expected a positive whole number of MiB, found 0.5".to_string()
+            )
+        );
+        assert_eq!(
+            message("inputs_dir = \"src"),
+            (1, "unterminated string".to_string())
+        );
+        assert_eq!(
+            message("days = 25"),
+            (1, "unknown key \"days\"".to_string())
+        );
+        assert_eq!(
+            message("format = \"xml\""),
+            (
+                1,
+                "unknown format \"xml\", expected \"text\" or \"json\"".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_folder() {
+        let config = Config {
+            inputs_dir: PathBuf::from("private/inputs"),
+            ..Config::default()
+        };
+        assert_eq!(config.folder("inputs"), PathBuf::from("private/inputs"));
+        assert_eq!(config.folder("examples"), PathBuf::from("src/examples"));
+        assert_eq!(config.folder("puzzles"), PathBuf::from("src/puzzles"));
+        assert_eq!(config.folder("answers"), PathBuf::from("src/answers"));
+    }
+}
@@ -2,3 +2,486 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use crate::parse::{self, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// A point or a vector on a plane where `y` grows downwards, like the rows of a [`Grid`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Up, right, down and left.
+pub const DIRECTIONS_4: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+/// The four directions of [`DIRECTIONS_4`] and the four diagonals, clockwise from up.
+pub const DIRECTIONS_8: [Point; 8] = [
+    Point::UP,
+    Point::new(1, -1),
+    Point::RIGHT,
+    Point::new(1, 1),
+    Point::DOWN,
+    Point::new(-1, 1),
+    Point::LEFT,
+    Point::new(-1, -1),
+];
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(0, -1);
+    pub const RIGHT: Point = Point::new(1, 0);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// The direction of a letter: `U`, `R`, `D` and `L`, or `N`, `E`, `S` and `W`.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'U' | 'N' => Some(Point::UP),
+            'R' | 'E' => Some(Point::RIGHT),
+            'D' | 'S' => Some(Point::DOWN),
+            'L' | 'W' => Some(Point::LEFT),
+            _ => None,
+        }
+    }
+
+    /// The sign of both coordinates, e.g. the single step towards a point that is this far away.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The number of steps to `other` when moving along the axes.
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps to `other` when diagonal steps are allowed.
+    pub fn chebyshev_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Turns a direction by 90 degrees clockwise, e.g. up to right.
+    pub fn rotate_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Turns a direction by 90 degrees counterclockwise, e.g. up to left.
+    pub fn rotate_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    /// The points up, right, down and left of this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        DIRECTIONS_4
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Like [`Point::neighbours`], including the diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        DIRECTIONS_8
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Parses a move like `R 4` into its direction and its number of steps.
+pub fn parse_move(s: &str) -> Result<(Point, usize), ParseError> {
+    let mut fields = parse::scan(s, "{} {}")?;
+    let letter = fields.next_str()?;
+
+    let mut letters = letter.chars();
+    let direction = match (letters.next(), letters.next()) {
+        (Some(letter), None) => Point::from_letter(letter),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ParseError::new(format!("expected a direction, found \"{}\"", letter)).at_column(1)
+    })?;
+
+    Ok((direction, fields.parse()?))
+}
+
+/// A position in a [`Grid`], as `(x, y)` with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with one row per line, mapping every character to a cell.
+    /// Fails if the rows differ in width.
+    ///
+    /// ```ignore
+    /// let trees = Grid::parse(input, |c| c.to_digit(10).unwrap())?;
+    /// ```
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in crate::tracked_lines(input) {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let row_width = cells.len() - len;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(format!(
+                        "expected a row of width {}, found width {}",
+                        width, row_width
+                    ))
+                    .at_line(height + 1));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at a position, `None` outside of the grid.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.1 * self.width + position.0])
+    }
+
+    /// The position one step from `position` in `direction`, `None` if it leaves the grid.
+    pub fn step(&self, (x, y): Position, direction: Point) -> Option<Position> {
+        let next = (
+            x.checked_add_signed(direction.x)?,
+            y.checked_add_signed(direction.y)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The positions up, right, down and left of `position` that are inside the grid.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_4
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Like [`Grid::neighbours`], including the diagonals.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_8
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions from `position` in `direction` up to the edge, excluding `position` itself.
+    pub fn ray(&self, position: Position, direction: Point) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.step(position, direction), move |&next| {
+            self.step(next, direction)
+        })
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            y < self.height,
+            "row {} is outside of the {}x{} grid",
+            y,
+            self.width,
+            self.height
+        );
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside of the {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    fn index_of(&self, position: Position) -> usize {
+        assert!(
+            self.contains(position),
+            "position {:?} is outside of the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+        position.1 * self.width + position.0
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let i = self.index_of(position);
+        &mut self.cells[i]
+    }
+}
+
+/// Renders the cells row by row, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::parse("123\n456\n", |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_point() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a * 2, Point::new(-2, 4));
+        assert_eq!((a - b).signum(), Point::new(1, -1));
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!(a.to_string(), "(1, -2)");
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Point::UP.rotate_right(), Point::RIGHT);
+        assert_eq!(Point::LEFT.rotate_right(), Point::UP);
+        assert_eq!(Point::UP.rotate_left(), Point::LEFT);
+        for direction in DIRECTIONS_8 {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+        }
+    }
+
+    #[test]
+    fn test_point_neighbours() {
+        let neighbours: Vec<Point> = Point::new(2, 2).neighbours().collect();
+        assert_eq!(
+            neighbours,
+            [
+                Point::new(2, 1),
+                Point::new(3, 2),
+                Point::new(2, 3),
+                Point::new(1, 2)
+            ]
+        );
+        assert!(Point::ZERO
+            .neighbours8()
+            .all(|p| p.chebyshev_distance(Point::ZERO) == 1));
+        assert_eq!(Point::ZERO.neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(parse_move("R 4"), Ok((Point::RIGHT, 4)));
+        assert_eq!(parse_move("U 12"), Ok((Point::UP, 12)));
+        assert!(parse_move("RR 4").is_err());
+        assert_eq!(
+            parse_move("X 4").unwrap_err().to_string(),
+            "column 1: expected a direction, found \"X\""
+        );
+        assert_eq!(
+            parse_move("R x").unwrap_err().to_string(),
+            "column 3: expected usize, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            Grid::parse("12\n3", |c| c).unwrap_err().to_string(),
+            "line 2: expected a row of width 2, found width 1"
+        );
+        assert_eq!(Grid::parse("", |c| c).unwrap().width(), 0);
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = grid();
+        assert_eq!(grid.get((0, 1)), Some(&4));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        grid[(1, 0)] = 0;
+        *grid.get_mut((2, 0)).unwrap() += 1;
+        assert_eq!(grid.to_string(), "104\n456");
+        assert_eq!(grid.get_mut((5, 5)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "position (3, 0) is outside of the 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = grid()[(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(grid.row(1).collect::<Vec<_>>(), [&4, &5, &6]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+        assert_eq!(
+            grid.ray((0, 0), Point::RIGHT).collect::<Vec<_>>(),
+            [(1, 0), (2, 0)]
+        );
+        assert_eq!(
+            grid.ray((0, 0), Point::new(1, 1)).collect::<Vec<_>>(),
+            [(1, 1)]
+        );
+        assert_eq!(grid.ray((0, 0), Point::UP).count(), 0);
+        assert_eq!(grid.iter().map(|(_, cell)| cell).sum::<u32>(), 21);
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..");
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside of the 3x2 grid")]
+    fn test_row_out_of_bounds() {
+        let _ = grid().row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the 3x2 grid")]
+    fn test_column_out_of_bounds() {
+        let _ = grid().column(3);
+    }
+}
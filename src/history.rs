@@ -0,0 +1,345 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * A local history of timings, so optimizations can be compared across commits.
+ * The history file has one tab-separated line per part and run:
+ * `commit  date  year  day  part  iterations  profile  elapsed_ns`.
+ */
+use crate::client::civil_from_days;
+use crate::{read_path, PartResult, ReadError};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The timing of one part in one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// The short hash of the commit the run was made on, suffixed with `-dirty` for uncommitted changes.
+    pub commit: String,
+    /// The UTC time of the run, e.g. `2022-12-08T06:12:45Z`.
+    pub date: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The number of timed runs, 1 if the part was not benchmarked.
+    pub iterations: u32,
+    /// The build profile of the run, see [`build_profile`].
+    pub profile: String,
+    /// The time of a single run, or the median when benchmarked.
+    pub elapsed: Duration,
+}
+
+impl Record {
+    /// A record of a solved part, `None` if the part has no answer to time.
+    pub fn from_result(result: &PartResult, commit: &str, date: &str) -> Option<Self> {
+        result.answer.as_ref()?;
+        Some(Record {
+            commit: commit.to_string(),
+            date: date.to_string(),
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            iterations: iterations(result),
+            profile: build_profile().to_string(),
+            elapsed: result.elapsed,
+        })
+    }
+
+    /// Whether a result was measured like this record: as often, and with the same build profile.
+    /// Other timings can't be compared to it.
+    pub fn is_comparable(&self, result: &PartResult) -> bool {
+        self.iterations == iterations(result) && self.profile == build_profile()
+    }
+
+    /// How the timing was measured, e.g. `100 runs, release`.
+    pub fn measurement(&self) -> String {
+        describe_measurement(self.iterations, &self.profile)
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.commit,
+            self.date,
+            self.year,
+            self.day,
+            self.part,
+            self.iterations,
+            self.profile,
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+fn iterations(result: &PartResult) -> u32 {
+    result.benchmark.map_or(1, |bench| bench.iterations)
+}
+
+/// How a result of this run is measured, e.g. `1 run, debug`.
+pub fn measurement(result: &PartResult) -> String {
+    describe_measurement(iterations(result), build_profile())
+}
+
+fn describe_measurement(iterations: u32, profile: &str) -> String {
+    format!(
+        "{} run{}, {}",
+        iterations,
+        if iterations == 1 { "" } else { "s" },
+        profile
+    )
+}
+
+/// The build profile that this binary was compiled with, `debug` or `release`.
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [commit, date, year, day, part, iterations, profile, elapsed_ns] = fields[..] else {
+            return Err(format!("expected 8 fields, found {}", fields.len()));
+        };
+
+        fn number<T: FromStr>(field: &str) -> Result<T, String> {
+            field
+                .parse()
+                .map_err(|_| format!("invalid number \"{}\"", field))
+        }
+
+        Ok(Record {
+            commit: commit.to_string(),
+            date: date.to_string(),
+            year: number(year)?,
+            day: number(day)?,
+            part: number(part)?,
+            iterations: number(iterations)?,
+            profile: profile.to_string(),
+            elapsed: Duration::from_nanos(number(elapsed_ns)?),
+        })
+    }
+}
+
+/// Parses a history file. Lines that can't be parsed are reported by their 1-based number.
+pub fn parse(content: &str) -> Result<Vec<Record>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect()
+}
+
+/// Reads the history file. A missing file is an empty history.
+pub fn read(path: &Path) -> Result<Vec<Record>, String> {
+    match read_path(path) {
+        Ok(content) => parse(&content).map_err(|e| format!("{}, {}", path.display(), e)),
+        Err(ReadError::NotFound(_)) => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let lines: String = records.iter().map(|r| r.to_line() + "\n").collect();
+    file.write_all(lines.as_bytes())
+}
+
+/// The commit that is checked out, `unknown` outside of a git repository.
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::project_dir())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+    };
+
+    let Some(output) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.stdout.is_empty() => commit + "-dirty",
+        _ => commit,
+    }
+}
+
+/// The current UTC time, formatted like `2022-12-08T06:12:45Z`.
+pub fn now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    format_date(secs)
+}
+
+fn format_date(secs: u64) -> String {
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let secs_of_day = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Which recorded timings a run is compared to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recent timing of each part.
+    Last,
+    /// The most recent timing of each part on a commit, given as a hash prefix.
+    Commit(String),
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expected a commit or \"last\"".to_string()),
+            "last" => Ok(Baseline::Last),
+            commit => Ok(Baseline::Commit(commit.to_string())),
+        }
+    }
+}
+
+impl Baseline {
+    /// Finds the baseline timing of a part.
+    pub fn find<'a>(
+        &self,
+        records: &'a [Record],
+        year: u16,
+        day: u8,
+        part: u8,
+    ) -> Option<&'a Record> {
+        records.iter().rev().find(|r| {
+            (r.year, r.day, r.part) == (year, day, part)
+                && match self {
+                    Baseline::Last => true,
+                    Baseline::Commit(commit) => r.commit.starts_with(commit.as_str()),
+                }
+        })
+    }
+}
+
+/// The relative change from `before` to `after`, e.g. `0.25` for 25% slower.
+pub fn relative_change(before: Duration, after: Duration) -> f64 {
+    if before.is_zero() {
+        return 0.0;
+    }
+    after.as_secs_f64() / before.as_secs_f64() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, part: u8, elapsed_us: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            date: "2022-12-08T06:12:45Z".to_string(),
+            year: 2022,
+            day,
+            part,
+            iterations: 100,
+            profile: "release".to_string(),
+            elapsed: Duration::from_micros(elapsed_us),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let record = record("1a2b3c4-dirty", 8, 2, 12);
+        let line = record.to_line();
+        assert_eq!(
+            line,
+            "1a2b3c4-dirty\t2022-12-08T06:12:45Z\t2022\t8\t2\t100\trelease\t12000"
+        );
+        assert_eq!(
+            parse(&format!("{}\n\n{}\n", line, line)),
+            Ok(vec![record.clone(), record])
+        );
+
+        assert_eq!(
+            parse("1a2b3c4\t2022-12-08T06:12:45Z\t2022\t8\t2\t100\t12000"),
+            Err("line 1: expected 8 fields, found 7".to_string())
+        );
+        assert_eq!(
+            parse("1a2b3c4\t2022-12-08T06:12:45Z\t2022\t8\t2\t100\trelease\tfast"),
+            Err("line 1: invalid number \"fast\"".to_string())
+        );
+        assert_eq!(
+            parse("1a2b3c4\t2022-12-08T06:12:45Z\t2022\t300\t2\t100\trelease\t12000"),
+            Err("line 1: invalid number \"300\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_baseline() {
+        let records = [
+            record("aaaaaaa", 8, 1, 30),
+            record("bbbbbbb", 8, 1, 20),
+            record("bbbbbbb", 8, 2, 40),
+            record("ccccccc", 8, 1, 10),
+        ];
+
+        assert_eq!(Baseline::Last.find(&records, 2022, 8, 1), Some(&records[3]));
+        assert_eq!(Baseline::Last.find(&records, 2022, 8, 2), Some(&records[2]));
+        assert_eq!(Baseline::Last.find(&records, 2021, 8, 1), None);
+
+        let baseline: Baseline = "aaa".parse().unwrap();
+        assert_eq!(baseline.find(&records, 2022, 8, 1), Some(&records[0]));
+        assert_eq!(baseline.find(&records, 2022, 8, 2), None);
+    }
+
+    #[test]
+    fn test_is_comparable() {
+        let result = PartResult {
+            answer: Some("42".to_string()),
+            ..PartResult::unsolved(2022, 8, 1)
+        };
+        let mut record = Record::from_result(&result, "aaaaaaa", "2022-12-08T06:12:45Z").unwrap();
+        assert!(record.is_comparable(&result));
+        assert_eq!(measurement(&result), format!("1 run, {}", build_profile()));
+
+        record.iterations = 100;
+        assert!(!record.is_comparable(&result));
+        assert!(record.measurement().starts_with("100 runs, "));
+
+        record.iterations = 1;
+        record.profile = "bench".to_string();
+        assert!(!record.is_comparable(&result));
+    }
+
+    #[test]
+    fn test_relative_change() {
+        let us = Duration::from_micros;
+        assert_eq!(relative_change(us(100), us(125)), 0.25);
+        assert_eq!(relative_change(us(100), us(50)), -0.5);
+        assert_eq!(relative_change(Duration::ZERO, us(50)), 0.0);
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date(1670479965), "2022-12-08T06:12:45Z");
+    }
+}
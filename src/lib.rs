@@ -3,92 +3,740 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use config::Config;
+use limits::Limits;
 use std::env;
+use std::fmt::{Debug, Display, Formatter};
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+pub mod client;
+pub mod config;
 pub mod helpers;
+pub mod history;
+pub mod limits;
+pub mod panics;
+pub mod parse;
+pub mod puzzle;
+pub mod readme;
+
+pub use panics::tracked_lines;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
+/// The default of `bench_iterations` in `aoc.toml`.
+pub const DEFAULT_BENCH_ITERATIONS: u32 = 100;
+
+/// How often a solver is run to time it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing {
+    /// Time a single run.
+    Once,
+    /// Time this many runs after a warmup.
+    Bench(u32),
+}
+
+/// Timing statistics over repeated runs of a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Benchmark {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Benchmark {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Benchmark {
+            iterations: n as u32,
+            min: sorted[0],
+            median,
+            mean,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The outcome of running one part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    /// The time of a single run, or the median when benchmarking.
+    pub elapsed: Duration,
+    pub benchmark: Option<Benchmark>,
+    pub verdict: Verdict,
+    /// Set if the part was stopped before it returned an answer.
+    pub failure: Option<Failure>,
+}
+
+/// How an answer compares to the answer recorded in `src/answers/YYYY/NN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// No answer recorded, or the part is not solved.
+    Unknown,
+}
+
+/// Why a part was stopped, see [`limits`] and [`panics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part ran longer than this time limit.
+    Timeout(Duration),
+    /// The part allocated more than this many bytes.
+    OutOfMemory(usize),
+    /// The solver panicked, e.g. on an input it can't parse.
+    Panic {
+        message: String,
+        /// Where in the code it panicked, e.g. `src/y2022/05.rs:18:44`.
+        location: String,
+        /// The 1-based number and the text of the input line that was being parsed, if known.
+        line: Option<(usize, String)>,
+    },
+}
+
+impl Failure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Failure::Timeout(_) => "timeout",
+            Failure::OutOfMemory(_) => "oom",
+            Failure::Panic { .. } => "panic",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Timeout(limit) => write!(f, "TIMEOUT: still running after {:.2?}", limit),
+            Failure::OutOfMemory(limit) => {
+                write!(
+                    f,
+                    "OOM: allocated more than {} MiB",
+                    limit / limits::MEBIBYTE
+                )
+            }
+            Failure::Panic {
+                message,
+                location,
+                line,
+            } => {
+                write!(f, "PANIC: {}", message)?;
+                if !location.is_empty() {
+                    write!(f, ", at {}", location)?;
                 }
-                None => {
-                    println!("not solved.")
+                match line {
+                    Some((number, text)) => {
+                        write!(f, "\non input line {}: {:?}", number, text)
+                    }
+                    None => Ok(()),
                 }
             }
         }
+    }
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Runs a solver on the input, timing only the solver itself.
+pub fn run_part<T: Display>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T>,
+    input: &str,
+    timing: Timing,
+) -> PartResult {
+    let timer = Instant::now();
+    let answer = solver(input);
+    let mut elapsed = timer.elapsed();
+    let mut benchmark = None;
+
+    if let (Timing::Bench(iterations), Some(_)) = (timing, &answer) {
+        for _ in 0..(iterations / 10).max(1) {
+            solver(input);
+        }
+
+        let samples: Vec<Duration> = (0..iterations.max(1))
+            .map(|_| {
+                let timer = Instant::now();
+                solver(input);
+                timer.elapsed()
+            })
+            .collect();
+
+        let stats = Benchmark::from_samples(&samples);
+        elapsed = stats.median;
+        benchmark = Some(stats);
+    }
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer: answer.map(|answer| answer.to_string()),
+        elapsed,
+        benchmark,
+        verdict: Verdict::Unknown,
+        failure: None,
+    }
+}
+
+impl PartResult {
+    /// A part that was not run, e.g. because the day has no solution yet.
+    pub fn unsolved(year: u16, day: u8, part: u8) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            benchmark: None,
+            verdict: Verdict::Unknown,
+            failure: None,
+        }
+    }
+
+    /// A part that was stopped for exceeding a limit.
+    pub fn failed(year: u16, day: u8, part: u8, failure: Failure) -> Self {
+        PartResult {
+            failure: Some(failure),
+            ..PartResult::unsolved(year, day, part)
+        }
+    }
+
+    /// Compares the answer to the recorded answers of its day.
+    pub fn check(&mut self, answers: &Answers) {
+        let expected = answers[self.part as usize - 1].as_ref();
+        self.verdict = match (&self.answer, expected) {
+            (Some(answer), Some(expected)) if answer == expected => Verdict::Correct,
+            (Some(_), Some(expected)) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            _ => Verdict::Unknown,
+        };
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Serializes the result as a single-line JSON object.
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(answer),
+            None => "null".to_string(),
+        };
+
+        let benchmark = match &self.benchmark {
+            Some(bench) => format!(
+                "{{\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"std_dev_ns\":{}}}",
+                bench.iterations,
+                bench.min.as_nanos(),
+                bench.median.as_nanos(),
+                bench.mean.as_nanos(),
+                bench.std_dev.as_nanos()
+            ),
+            None => "null".to_string(),
+        };
+
+        let error = match &self.failure {
+            Some(failure) => json_string(&failure.to_string()),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{},\"verdict\":\"{}\",\"elapsed_ns\":{},\"benchmark\":{},\"error\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            match (&self.failure, self.is_solved()) {
+                (Some(failure), _) => failure.as_str(),
+                (None, true) => "solved",
+                (None, false) => "unsolved",
+            },
+            answer,
+            self.verdict.as_str(),
+            self.elapsed.as_nanos(),
+            benchmark,
+            error
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn print_result(result: &PartResult, format: Format) {
+    if format == Format::Json {
+        println!("{}", result.to_json());
+        return;
+    }
+
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+
+    if let Some(failure) = &result.failure {
+        println!("{}", failure);
+        return;
+    }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    let answer = match &result.answer {
+        Some(answer) => answer,
+        None => {
+            println!("not solved.");
+            return;
+        }
+    };
+
+    let timing = match &result.benchmark {
+        Some(bench) => format!(
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?}, σ: {:.2?} over {} runs",
+            bench.median, bench.min, bench.mean, bench.std_dev, bench.iterations
+        ),
+        None => format!("elapsed: {:.2?}", result.elapsed),
+    };
+
+    let verdict = match &result.verdict {
+        Verdict::Correct => " ✅".to_string(),
+        Verdict::Wrong { expected } => format!(" ❌ expected: {}", expected),
+        Verdict::Unknown => "".to_string(),
+    };
+
+    println!(
+        "{} {}({}){}{}",
+        answer, ANSI_ITALIC, timing, ANSI_RESET, verdict
+    );
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable text with ANSI styling.
+    Text,
+    /// One JSON object per line and part.
+    Json,
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl FromStr for Format {
+    type Err = String;
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format \"{}\", expected \"text\" or \"json\"",
+                s
+            )),
+        }
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Command-line options shared by the `solve` and `all` runners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub year: u16,
+    pub timing: Timing,
+    pub format: Format,
+    pub limits: Limits,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
+impl Options {
+    /// Reads `[-y|--year <year>] [--bench [--iterations <n>]] [--format text|json]
+    /// [--timeout <seconds>] [--memory-limit <MiB>]`, with defaults from `aoc.toml`.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Options::parse_with(args, config::get())
+    }
+
+    pub fn parse_with(
+        args: &mut pico_args::Arguments,
+        config: &Config,
+    ) -> Result<Self, pico_args::Error> {
+        let year = args.opt_value_from_str(["-y", "--year"])?;
+
+        let timing = if args.contains("--bench") {
+            let iterations = args.opt_value_from_str("--iterations")?;
+            Timing::Bench(iterations.unwrap_or(config.bench_iterations))
         } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+            Timing::Once
+        };
+
+        Ok(Options {
+            year: year.unwrap_or_else(|| default_year_with(config)),
+            timing,
+            format: args
+                .opt_value_from_str("--format")?
+                .unwrap_or(config.format),
+            limits: Limits {
+                timeout: args
+                    .opt_value_from_fn("--timeout", limits::parse_timeout)?
+                    .or(config.timeout),
+                memory: args
+                    .opt_value_from_fn("--memory-limit", limits::parse_memory_limit)?
+                    .or(config.memory_limit),
+            },
+        })
+    }
+
+    pub fn from_env() -> Self {
+        match Options::parse(&mut pico_args::Arguments::from_env()) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                std::process::exit(1);
             }
         }
+    }
+}
+
+/// The year commands use when none is passed: `AOC_YEAR` if set, else `year` in `aoc.toml`,
+/// else the most recent event.
+pub fn default_year() -> u16 {
+    default_year_with(config::get())
+}
+
+fn default_year_with(config: &Config) -> u16 {
+    env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.trim().parse().ok())
+        .or(config.year)
+        .unwrap_or_else(client::latest_event_year)
+}
+
+/// Parses a selection of days like `3,5,7-10` into sorted, distinct days.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1 to 25", day.trim())),
+    };
+
+    let mut days = vec![];
+    for item in spec.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("invalid range \"{}\"", item.trim()));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Fails on arguments that were left unread, like typos or cargo flags passed after the day.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused: Vec<String> = args
+        .finish()
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+
+    let cause = match unused.as_slice() {
+        [] => return Ok(()),
+        // cargo passes everything after the day to the binary.
+        _ if unused.iter().any(|arg| arg == "--release") => {
+            "`--release` goes before the day, e.g. `cargo solve --release 7`".to_string()
+        }
+        _ => format!("unknown arguments: {}", unused.join(" ")),
+    };
+    Err(pico_args::Error::ArgumentParsingFailed { cause })
+}
+
+/// A solver with its answer type erased, so days with different answer types can be run alike.
+pub type Solver = fn(&str, Timing) -> PartResult;
+
+/// Both parts of a day's solution, as registered in `SOLUTIONS` by `build.rs`.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+    /// Shows how the solution works on an input, see `cargo solve <day> --visualize`.
+    pub visualize: Option<fn(&str)>,
+}
+
+impl Solution {
+    /// Runs a part (1 or 2) within the limits. A part that exceeds them or panics is reported
+    /// as failed.
+    pub fn run(&self, part: u8, input: &Arc<str>, timing: Timing, limits: Limits) -> PartResult {
+        let solver = match part {
+            1 => self.part_one,
+            _ => self.part_two,
+        };
+        let input = Arc::clone(input);
+        let run = move || panics::catch(&input, || solver(&input, timing));
+
+        let result = if limits.is_unlimited() {
+            run()
+        } else {
+            limits::run_limited(limits, run).and_then(|result| result)
+        };
+        result.unwrap_or_else(|failure| PartResult::failed(self.year, self.day, part, failure))
+    }
+}
+
+/// Builds a [`Solution`] from a module that exposes `part_one` and `part_two`, and
+/// `visualize` if `visualize` is passed.
+#[macro_export]
+macro_rules! solution {
+    (@build $year:expr, $day:expr, [$($module:ident)::+], $visualize:expr) => {
+        $crate::Solution {
+            year: $year,
+            day: $day,
+            part_one: |input, timing| {
+                $crate::run_part($year, $day, 1, $($module)::+::part_one, input, timing)
+            },
+            part_two: |input, timing| {
+                $crate::run_part($year, $day, 2, $($module)::+::part_two, input, timing)
+            },
+            visualize: $visualize,
+        }
+    };
+    ($year:expr, $day:expr, $($module:ident)::+) => {
+        $crate::solution!(@build $year, $day, [$($module)::+], None)
+    };
+    ($year:expr, $day:expr, $($module:ident)::+, visualize) => {
+        $crate::solution!(@build $year, $day, [$($module)::+], Some($($module)::+::visualize))
+    };
+}
+
+/// Runs both parts of a solution, checks them against `answers` and prints them.
+pub fn solve_day(
+    solution: &Solution,
+    input: &str,
+    answers: &Answers,
+    options: Options,
+) -> Vec<PartResult> {
+    let input: Arc<str> = Arc::from(input);
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let mut result = solution.run(part, &input, options.timing, options.limits);
+            result.check(answers);
+            print_result(&result, options.format);
+            result
+        })
+        .collect()
+}
+
+/// The recorded correct answers of a day's parts, `None` where unknown.
+pub type Answers = [Option<String>; 2];
+
+/// Parses an answers file: part one on the first line, part two on the second.
+/// Blank lines are unknown answers, line breaks within an answer are written as `\n`.
+pub fn parse_answers(content: &str) -> Answers {
+    let mut lines = content.lines().map(|l| {
+        let answer = l.trim_end().replace("\\n", "\n");
+        (!answer.is_empty()).then_some(answer)
+    });
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// The inverse of [`parse_answers`].
+pub fn format_answers(answers: &Answers) -> String {
+    answers
+        .iter()
+        .map(|answer| answer.as_deref().unwrap_or_default().replace('\n', "\\n") + "\n")
+        .collect()
+}
+
+/// Records an accepted answer in `src/answers/YYYY/NN.txt`, keeping the other part's answer.
+pub fn write_answer(year: u16, day: u8, part: u8, answer: &str) -> std::io::Result<()> {
+    let mut answers = read_answers(year, day);
+    answers[part as usize - 1] = Some(answer.to_string());
+    let path = get_path("answers", year, day);
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, format_answers(&answers))
+}
+
+/// Reads `src/answers/YYYY/NN.txt`. A missing file means no answers are known.
+pub fn read_answers(year: u16, day: u8) -> Answers {
+    match fs::read_to_string(get_path("answers", year, day)) {
+        Ok(content) => parse_answers(&content),
+        Err(_) => [None, None],
+    }
+}
+
+/// The root of the project that the template was built from. Relative paths are resolved
+/// against it, so commands work from any working directory.
+pub fn project_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The path of a day's file in a per-year folder, e.g. `src/inputs/2022/07.txt`.
+/// The inputs and examples folders are set in `aoc.toml`.
+pub fn get_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    project_dir()
+        .join(config::get().folder(folder))
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    /// The file does not exist, e.g. an input that is not downloaded yet.
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::NotFound(path) => write!(f, "\"{}\" does not exist", path.display()),
+            ReadError::Io(path, e) => write!(f, "could not read \"{}\": {}", path.display(), e),
+            ReadError::Stdin(e) => write!(f, "could not read stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Reads a day's file, e.g. its input with `read_file("inputs", 2022, 7)`.
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    read_path(&get_path(folder, year, day))
+}
+
+pub fn read_path(path: &Path) -> Result<String, ReadError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => ReadError::NotFound(path.to_path_buf()),
+        _ => ReadError::Io(path.to_path_buf(), e),
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// The path of a named example, e.g. `src/examples/2022/06-b.txt` for `"b"`.
+/// Days with more than one example name them instead of using `NN.txt`.
+pub fn get_example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    get_path("examples", year, day).with_file_name(format!("{:02}-{}.txt", day, name))
+}
+
+pub fn read_example(year: u16, day: u8, name: &str) -> Result<String, ReadError> {
+    read_path(&get_example_path(year, day, name))
+}
+
+/// Runs a solver on named examples and compares each result with its expected answer.
+/// Panics with every mismatch, so a single test covers all examples of a part.
+///
+/// ```ignore
+/// advent_of_code::check_examples(2022, 6, part_one, &[("a", Some(7)), ("b", Some(5))]);
+/// ```
+pub fn check_examples<T: PartialEq + Debug>(
+    year: u16,
+    day: u8,
+    solver: impl Fn(&str) -> T,
+    expected: &[(&str, T)],
+) {
+    let failures: Vec<String> = expected
+        .iter()
+        .filter_map(|(name, expected)| {
+            let input = match read_example(year, day, name) {
+                Ok(input) => input,
+                Err(e) => return Some(format!("example \"{}\": {}", name, e)),
+            };
+            let actual = solver(&input);
+            (actual != *expected).then(|| {
+                format!(
+                    "example \"{}\": expected {:?}, got {:?}",
+                    name, expected, actual
+                )
+            })
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} examples failed:\n{}",
+        failures.len(),
+        expected.len(),
+        failures.join("\n")
+    );
+}
+
+/// Where a day run reads its input from, as passed to `--input`: a path, or `-` for stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expected a path, or \"-\" for stdin".to_string()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, ReadError> {
+        match self {
+            InputSource::File(path) => read_path(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()).map_err(ReadError::Stdin),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +744,211 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_run_part() {
+        let result = run_part(2022, 3, 2, |input| Some(input.len()), "abc", Timing::Once);
+        assert_eq!(result.year, 2022);
+        assert_eq!(result.day, 3);
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer, Some("3".to_string()));
+        assert_eq!(result.benchmark, None);
+
+        let result = run_part(2022, 3, 1, |_| None::<u32>, "abc", Timing::Bench(10));
+        assert_eq!(result.answer, None);
+        assert_eq!(result.benchmark, None);
+
+        let result = run_part(
+            2022,
+            3,
+            1,
+            |input| Some(input.len()),
+            "abc",
+            Timing::Bench(10),
         );
+        assert_eq!(result.benchmark.unwrap().iterations, 10);
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+    #[test]
+    fn test_to_json() {
+        let result = PartResult {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some("#.\n\"x\"".to_string()),
+            elapsed: Duration::from_micros(12),
+            benchmark: None,
+            verdict: Verdict::Correct,
+            failure: None,
+        };
+        assert_eq!(
+            result.to_json(),
+            r##"{"year":2022,"day":10,"part":2,"status":"solved","answer":"#.\n\"x\"","verdict":"correct","elapsed_ns":12000,"benchmark":null,"error":null}"##
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        assert_eq!(
+            PartResult::unsolved(2022, 3, 1).to_json(),
+            r#"{"year":2022,"day":3,"part":1,"status":"unsolved","answer":null,"verdict":"unknown","elapsed_ns":0,"benchmark":null,"error":null}"#
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        assert_eq!(
+            PartResult::failed(2022, 9, 2, Failure::Timeout(Duration::from_secs(1))).to_json(),
+            r#"{"year":2022,"day":9,"part":2,"status":"timeout","answer":null,"verdict":"unknown","elapsed_ns":0,"benchmark":null,"error":"TIMEOUT: still running after 1.00s"}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("24000\n45000\n"),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        assert_eq!(parse_answers("\nMCD"), [None, Some("MCD".to_string())]);
+        assert_eq!(parse_answers("7"), [Some("7".to_string()), None]);
+        assert_eq!(
+            parse_answers("7\n#.\\n.#"),
+            [Some("7".to_string()), Some("#.\n.#".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_format_answers() {
+        let answers = [None, Some("#.\n.#\n".to_string())];
+        assert_eq!(format_answers(&answers), "\n#.\\n.#\\n\n");
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = [Some("3".to_string()), Some("4".to_string())];
+
+        let mut result = run_part(2022, 3, 1, |input| Some(input.len()), "abc", Timing::Once);
+        result.check(&answers);
+        assert_eq!(result.verdict, Verdict::Correct);
+
+        let mut result = run_part(2022, 3, 2, |input| Some(input.len()), "abc", Timing::Once);
+        result.check(&answers);
+        assert_eq!(
+            result.verdict,
+            Verdict::Wrong {
+                expected: "4".to_string()
+            }
+        );
+
+        let mut result = run_part(2022, 3, 2, |_| None::<u32>, "abc", Timing::Once);
+        result.check(&answers);
+        assert_eq!(result.verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let mut args = pico_args::Arguments::from_vec(vec![
+            "--format".into(),
+            "json".into(),
+            "--bench".into(),
+            "-y".into(),
+            "2021".into(),
+        ]);
+        assert_eq!(
+            Options::parse_with(&mut args, &Config::default()).unwrap(),
+            Options {
+                year: 2021,
+                timing: Timing::Bench(DEFAULT_BENCH_ITERATIONS),
+                format: Format::Json,
+                limits: Limits::default(),
+            }
+        );
+
+        let config = Config {
+            format: Format::Json,
+            bench_iterations: 7,
+            timeout: Some(Duration::from_secs(10)),
+            memory_limit: Some(512 << 20),
+            ..Config::default()
+        };
+        let mut args = pico_args::Arguments::from_vec(vec![
+            "--bench".into(),
+            "--timeout".into(),
+            "1.5".into(),
+        ]);
+        let options = Options::parse_with(&mut args, &config).unwrap();
+        assert_eq!(options.timing, Timing::Bench(7));
+        assert_eq!(options.format, Format::Json);
+        assert_eq!(
+            options.limits,
+            Limits {
+                timeout: Some(Duration::from_millis(1500)),
+                memory: Some(512 << 20),
+            }
         );
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--timeout".into(), "0".into()]);
+        assert!(Options::parse_with(&mut args, &config).is_err());
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--format".into(), "xml".into()]);
+        assert!(Options::parse_with(&mut args, &config).is_err());
+    }
+
+    #[test]
+    fn test_read_file() {
+        assert_eq!(
+            read_file("examples", 2022, 1).unwrap(),
+            fs::read_to_string(project_dir().join("src/examples/2022/01.txt")).unwrap()
+        );
+        assert!(matches!(
+            read_file("examples", 1999, 1),
+            Err(ReadError::NotFound(path)) if path.ends_with("src/examples/1999/01.txt")
+        ));
+    }
+
+    #[test]
+    fn test_get_example_path() {
+        assert!(get_example_path(2022, 6, "b").ends_with("src/examples/2022/06-b.txt"));
+    }
+
+    #[test]
+    fn test_check_examples() {
+        check_examples(2022, 6, str::len, &[("a", 31), ("b", 29)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "2 of 3 examples failed:\nexample \"b\": expected 0, got 29\nexample \"x\": "
+    )]
+    fn test_check_examples_mismatch() {
+        check_examples(2022, 6, str::len, &[("a", 31), ("b", 0), ("x", 0)]);
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "inputs/alice.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("inputs/alice.txt")))
+        );
+        assert!("".parse::<InputSource>().is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3,5,7-10"), Ok(vec![3, 5, 7, 8, 9, 10]));
+        assert_eq!(parse_days("25, 1-2, 2"), Ok(vec![1, 2, 25]));
+        assert_eq!(parse_days("4-4"), Ok(vec![4]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("10-7").is_err());
+        assert!(parse_days("3,").is_err());
+    }
+
+    #[test]
+    fn test_benchmark_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let bench = Benchmark::from_samples(&samples);
+        assert_eq!(bench.iterations, 4);
+        assert_eq!(bench.min, Duration::from_micros(1));
+        assert_eq!(bench.median, Duration::from_nanos(2500));
+        assert_eq!(bench.mean, Duration::from_nanos(2500));
+        assert_eq!(bench.std_dev.as_nanos(), 1118);
+
+        let bench = Benchmark::from_samples(&samples[..3]);
+        assert_eq!(bench.median, Duration::from_micros(3));
     }
 }
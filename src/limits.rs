@@ -0,0 +1,267 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Time and memory limits for solvers, so a part that loops forever or allocates without bound
+ * is reported as TIMEOUT or OOM instead of stalling the whole run.
+ */
+use crate::Failure;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, OnceCell};
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long and how much memory a part may use. `None` is unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Applies to the whole run of a part, including its benchmark iterations.
+    pub timeout: Option<Duration>,
+    /// The bytes a part may have allocated at any time. Only enforced in binaries that install
+    /// [`LimitedAllocator`] as their global allocator.
+    pub memory: Option<usize>,
+}
+
+impl Limits {
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.memory.is_none()
+    }
+}
+
+pub const MEBIBYTE: usize = 1 << 20;
+
+/// Parses a time limit in seconds, like `2` or `0.5`, for `--timeout` and `timeout` in `aoc.toml`.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|&secs| secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| "expected a positive number of seconds".to_string())
+}
+
+/// Parses a memory limit in MiB into bytes, for `--memory-limit` and `memory_limit` in `aoc.toml`.
+pub fn parse_memory_limit(s: &str) -> Result<usize, String> {
+    s.parse::<usize>()
+        .ok()
+        .filter(|&mib| mib > 0)
+        .and_then(|mib| mib.checked_mul(MEBIBYTE))
+        .ok_or_else(|| "expected a positive whole number of MiB".to_string())
+}
+
+thread_local! {
+    /// The bytes this thread allocated and has not freed yet.
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    /// The memory limit of this thread and the flag that reports exceeding it.
+    static MEMORY_LIMIT: OnceCell<(usize, Arc<AtomicBool>)> = const { OnceCell::new() };
+}
+
+/// The system allocator, counting the live allocations of every thread so that
+/// [`run_limited`] can enforce memory limits. Every binary that runs solutions installs it, as
+/// limits only apply to allocations that go through it.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: LimitedAllocator = LimitedAllocator;
+/// ```
+pub struct LimitedAllocator;
+
+impl LimitedAllocator {
+    /// Counts `size` more bytes against the thread's limit.
+    ///
+    /// A failed allocation aborts the process, so allocations never fail here. A thread over its
+    /// limit raises its flag instead, which stops its part like a timeout does.
+    fn reserve(size: usize) {
+        let Ok(total) = ALLOCATED.try_with(|allocated| {
+            allocated.set(allocated.get().saturating_add(size));
+            allocated.get()
+        }) else {
+            return;
+        };
+
+        let _ = MEMORY_LIMIT.try_with(|memory_limit| {
+            if let Some((limit, exceeded)) = memory_limit.get() {
+                if total > *limit {
+                    exceeded.store(true, Ordering::Relaxed);
+                }
+            }
+        });
+    }
+
+    fn release(size: usize) {
+        // memory freed by another thread than the one that allocated it is not tracked.
+        let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get().saturating_sub(size)));
+    }
+}
+
+unsafe impl GlobalAlloc for LimitedAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LimitedAllocator::reserve(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        LimitedAllocator::reserve(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LimitedAllocator::release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            LimitedAllocator::reserve(new_size - layout.size());
+        } else {
+            LimitedAllocator::release(layout.size() - new_size);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+// how often a run with a memory limit is checked, as the solver thread can't report exceeding it.
+const MIN_POLL_INTERVAL: Duration = Duration::from_micros(50);
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+// the states of a thread started by `run_limited`.
+const RUNNING: u8 = 0;
+const STOPPED: u8 = 1;
+const DONE: u8 = 2;
+
+/// The threads of stopped parts that are still running.
+static STOPPED_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+/// How many parts were stopped by a limit but are still running in the background. They compete
+/// for the CPU, so the timings of parts that run after them may be skewed.
+pub fn stopped_still_running() -> usize {
+    STOPPED_RUNNING.load(Ordering::Relaxed)
+}
+
+/// Marks the thread of a part as done when it ends, even by panicking.
+struct Finish(Arc<AtomicU8>);
+
+impl Drop for Finish {
+    fn drop(&mut self) {
+        if self.0.swap(DONE, Ordering::Relaxed) == STOPPED {
+            STOPPED_RUNNING.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Runs `f` on its own thread and waits for its result within the limits.
+///
+/// Threads can't be stopped from the outside, so the thread of a part that exceeds a limit is
+/// left behind and counted in [`stopped_still_running`] until it ends.
+pub fn run_limited<T: Send + 'static>(
+    limits: Limits,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    let exceeded = Arc::new(AtomicBool::new(false));
+    let state = Arc::new(AtomicU8::new(RUNNING));
+    let timer = Instant::now();
+
+    let finish = Finish(Arc::clone(&state));
+    let thread_exceeded = Arc::clone(&exceeded);
+    let handle = thread::spawn(move || {
+        let _finish = finish;
+        if let Some(limit) = limits.memory {
+            MEMORY_LIMIT.with(|memory_limit| memory_limit.set((limit, thread_exceeded)).ok());
+        }
+        let _ = sender.send(f());
+    });
+
+    let stop = |failure: Failure| {
+        let stopped =
+            state.compare_exchange(RUNNING, STOPPED, Ordering::Relaxed, Ordering::Relaxed);
+        if stopped.is_ok() {
+            STOPPED_RUNNING.fetch_add(1, Ordering::Relaxed);
+        }
+        Err(failure)
+    };
+
+    loop {
+        // polling adds at most a tenth of the run time, and never skews the measured timings.
+        let wait = match limits.memory {
+            Some(_) => (timer.elapsed() / 10).clamp(MIN_POLL_INTERVAL, MAX_POLL_INTERVAL),
+            None => Duration::from_secs(3600),
+        };
+        let wait = match limits.timeout {
+            Some(timeout) => wait.min(timeout.saturating_sub(timer.elapsed())),
+            None => wait,
+        };
+
+        let received = receiver.recv_timeout(wait);
+        // a thread that went over its limit may still finish.
+        if let (true, Some(limit)) = (exceeded.load(Ordering::Relaxed), limits.memory) {
+            return stop(Failure::OutOfMemory(limit));
+        }
+
+        match received {
+            Ok(result) => return Ok(result),
+            Err(RecvTimeoutError::Disconnected) => {
+                // `f` panicked before sending its result.
+                let payload = handle
+                    .join()
+                    .expect_err("a solver thread ended without a result");
+                panic::resume_unwind(payload);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if let Some(timeout) = limits.timeout.filter(|&timeout| timer.elapsed() >= timeout) {
+            return stop(Failure::Timeout(timeout));
+        }
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: LimitedAllocator = LimitedAllocator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(timeout_ms: Option<u64>, memory: Option<usize>) -> Limits {
+        Limits {
+            timeout: timeout_ms.map(Duration::from_millis),
+            memory,
+        }
+    }
+
+    #[test]
+    fn test_run_limited() {
+        let result = run_limited(limits(Some(10_000), Some(1 << 20)), || "abc".len());
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let result = run_limited(limits(Some(50), None), || loop {
+            thread::sleep(Duration::from_millis(10));
+        });
+        assert_eq!(result, Err(Failure::Timeout(Duration::from_millis(50))));
+    }
+
+    #[test]
+    fn test_out_of_memory() {
+        // fails even though the part returns, as it went over the limit on the way.
+        let result = run_limited(limits(Some(10_000), Some(1 << 20)), || {
+            std::hint::black_box(vec![0_u8; 3 << 19]).len()
+        });
+        assert_eq!(result, Err(Failure::OutOfMemory(1 << 20)));
+    }
+
+    #[test]
+    fn test_far_out_of_memory() {
+        // allocations are never refused, so the process survives a part far over its limit.
+        let result = run_limited(limits(Some(10_000), Some(1 << 20)), || {
+            std::hint::black_box(vec![0_u8; 64 << 20]).len()
+        });
+        assert_eq!(result, Err(Failure::OutOfMemory(1 << 20)));
+    }
+}
@@ -2,45 +2,380 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::history::{self, Baseline, Record};
+use advent_of_code::limits::{self, LimitedAllocator, Limits};
+use advent_of_code::{
+    config, Answers, Format, Options, PartResult, ReadError, Solution, Timing, Verdict, ANSI_BOLD,
+    ANSI_ITALIC, ANSI_RESET,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+mod solutions;
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+#[global_allocator]
+static ALLOCATOR: LimitedAllocator = LimitedAllocator;
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+/// A day that can be run: it has a solution and an input.
+struct Day {
+    input: Arc<str>,
+    answers: Answers,
+    solution: &'static Solution,
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// One part of a day, the unit of work that is spread over the threads.
+struct Task<'a> {
+    day: &'a Day,
+    part: u8,
+}
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+struct Args {
+    options: Options,
+    /// The selected days, all days if empty.
+    days: Vec<u8>,
+    /// The selected part, both parts if `None`.
+    part: Option<u8>,
+    threads: Option<usize>,
+    /// Record the timings in the history file.
+    save: bool,
+    baseline: Option<Baseline>,
+    /// Overrides `regression_threshold` of `aoc.toml`, in percent.
+    threshold: Option<u32>,
+}
+
+/// Reads the shared options, plus `[--part 1|2] [--serial | --threads <n>]
+/// [--save] [--baseline <commit|last> [--threshold <percent>]] [days]`.
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let options = Options::parse(&mut args)?;
+
+    let part = args.opt_value_from_fn("--part", |part| match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err("expected 1 or 2"),
+    })?;
+
+    let threads = if args.contains("--serial") {
+        Some(1)
+    } else {
+        args.opt_value_from_str("--threads")?
+    };
+
+    let save = args.contains("--save");
+    let baseline = args.opt_value_from_str("--baseline")?;
+    let threshold = args.opt_value_from_str("--threshold")?;
+
+    let days = args
+        .opt_free_from_fn(advent_of_code::parse_days)?
+        .unwrap_or_default();
+    advent_of_code::finish_args(args)?;
+
+    Ok(Args {
+        options,
+        days,
+        part,
+        threads,
+        save,
+        baseline,
+        threshold,
+    })
+}
+
+/// Runs the tasks on `threads` threads and calls `report` with every result, in task order.
+/// A result is reported as soon as all tasks before it are done, so output streams in order.
+fn run_tasks(
+    tasks: &[Task],
+    timing: Timing,
+    limits: Limits,
+    threads: usize,
+    mut report: impl FnMut(PartResult),
+) {
+    let next_task = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(tasks.len()) {
+            let sender = sender.clone();
+            let next_task = &next_task;
+            scope.spawn(move || loop {
+                let i = next_task.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(i) else {
+                    break;
+                };
+                let day = task.day;
+                let mut result = day.solution.run(task.part, &day.input, timing, limits);
+                result.check(&day.answers);
+                if sender.send((i, result)).is_err() {
+                    break;
                 }
+            });
+        }
+        drop(sender);
+
+        let mut pending = HashMap::new();
+        for i in 0..tasks.len() {
+            while !pending.contains_key(&i) {
+                let (j, result) = receiver.recv().expect("a worker thread stopped early");
+                pending.insert(j, result);
+            }
+            report(pending.remove(&i).unwrap());
+        }
+    });
+}
+
+/// Prints how the timings changed since the baseline and lists the parts that got slower than the threshold.
+fn print_comparison(
+    results: &[PartResult],
+    history: &[Record],
+    baseline: &Baseline,
+    threshold: u32,
+) {
+    println!(
+        "{}Compared to {}:{} (threshold: {}%)",
+        ANSI_BOLD,
+        match baseline {
+            Baseline::Last => "the last recorded timings".to_string(),
+            Baseline::Commit(commit) => format!("commit {}", commit),
+        },
+        ANSI_RESET,
+        threshold
+    );
+
+    let mut regressions = vec![];
+
+    for result in results.iter().filter(|r| r.is_solved()) {
+        let name = format!("Day {:02} Part {}", result.day, result.part);
+        let Some(before) = baseline.find(history, result.year, result.day, result.part) else {
+            println!("{}: no baseline", name);
+            continue;
+        };
+        if !before.is_comparable(result) {
+            println!(
+                "{}: not comparable, {} vs {} on {}",
+                name,
+                history::measurement(result),
+                before.measurement(),
+                before.commit
             );
+            continue;
+        }
 
-            if is_empty {
-                0_f64
+        let change = history::relative_change(before.elapsed, result.elapsed);
+        let is_regression = change * 100.0 > threshold as f64;
+        println!(
+            "{}: {:.2?} → {:.2?} ({:+.1}%) {}vs {}{}{}",
+            name,
+            before.elapsed,
+            result.elapsed,
+            change * 100.0,
+            ANSI_ITALIC,
+            before.commit,
+            ANSI_RESET,
+            if is_regression {
+                " ⚠️ regression"
             } else {
-                advent_of_code::parse_exec_time(&output)
+                ""
+            }
+        );
+
+        if is_regression {
+            regressions.push(name);
+        }
+    }
+
+    if !regressions.is_empty() {
+        println!(
+            "{}Regressions:{} {}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            regressions.join(", ")
+        );
+    }
+}
+
+fn main() {
+    let Args {
+        options,
+        days,
+        part,
+        threads,
+        save,
+        baseline,
+        threshold,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo all -- 3,5,7-10 --part 2`");
+            std::process::exit(1);
+        }
+    };
+    let year = options.year;
+    let is_text = options.format == Format::Text;
+    let config = config::get();
+    let history_path = advent_of_code::project_dir().join(&config.bench_history);
+
+    // read before this run is saved, so `--baseline last` is the run before it.
+    let history = match (&baseline, history::read(&history_path)) {
+        (None, _) => vec![],
+        (Some(_), Ok(history)) => history,
+        (Some(_), Err(e)) => {
+            eprintln!("Failed to read benchmark history: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // benchmarks compete for the CPU when run side by side, so they run serially by default.
+    let threads = match (threads, options.timing) {
+        (Some(threads), _) => threads.max(1),
+        (None, Timing::Bench(_)) => 1,
+        (None, Timing::Once) => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    let selected = if days.is_empty() {
+        (1..=25).collect()
+    } else {
+        days
+    };
+
+    // days without a solution or an input are skipped.
+    let days: Vec<Day> = selected
+        .into_iter()
+        .filter_map(|day| {
+            let solution = solutions::find_solution(year, day)?;
+            match advent_of_code::read_file("inputs", year, day) {
+                Ok(input) => Some(Day {
+                    input: Arc::from(input),
+                    answers: advent_of_code::read_answers(year, day),
+                    solution,
+                }),
+                Err(ReadError::NotFound(_)) => None,
+                Err(e) => {
+                    eprintln!("Skipping day {:02}, failed to read input: {}", day, e);
+                    None
+                }
             }
         })
-        .sum();
+        .collect();
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let tasks: Vec<Task> = days
+        .iter()
+        .flat_map(|day| parts.iter().map(move |&part| Task { day, part }))
+        .collect();
+
+    if is_text {
+        println!("{}Advent of Code {}{}", ANSI_BOLD, year, ANSI_RESET);
+        if tasks.is_empty() {
+            println!("No selected day has both a solution and an input.");
+            return;
+        }
+    }
+
+    let mut results: Vec<PartResult> = Vec::with_capacity(tasks.len());
+    let timer = Instant::now();
+
+    run_tasks(&tasks, options.timing, options.limits, threads, |result| {
+        let is_new_day = results.last().map(|r| r.day) != Some(result.day);
+        if is_text && is_new_day {
+            println!("----------");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, result.day, ANSI_RESET);
+            println!("----------");
+        }
+        advent_of_code::print_result(&result, options.format);
+        results.push(result);
+    });
+
+    let wall_clock = timer.elapsed();
+
+    if save {
+        let (commit, date) = (history::git_commit(), history::now());
+        let records: Vec<Record> = results
+            .iter()
+            .filter_map(|result| Record::from_result(result, &commit, &date))
+            .collect();
+
+        match history::append(&history_path, &records) {
+            Ok(_) if is_text => println!(
+                "Saved {} timings of {} to \"{}\".",
+                records.len(),
+                commit,
+                history_path.display()
+            ),
+            Ok(_) => {}
+            Err(e) => eprintln!("could not save timings: {}", e),
+        }
+    }
+
+    if is_text {
+        print_summary(&results, wall_clock, threads);
+        if let Some(baseline) = &baseline {
+            let threshold = threshold.unwrap_or(config.regression_threshold);
+            print_comparison(&results, &history, baseline, threshold);
+        }
+    }
+
+    // a non-zero exit lets scripts notice wrong answers and parts that failed to finish.
+    let is_failed =
+        |r: &PartResult| r.failure.is_some() || matches!(r.verdict, Verdict::Wrong { .. });
+    if results.iter().any(is_failed) {
+        std::process::exit(1);
+    }
+}
+
+fn print_summary(results: &[PartResult], wall_clock: Duration, threads: usize) {
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
 
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}Total:{} {}{:.2}ms, wall-clock: {:.2}ms on {} thread{}{}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        wall_clock.as_secs_f64() * 1000_f64,
+        threads,
+        if threads == 1 { "" } else { "s" },
+        ANSI_RESET
     );
+
+    let wrong: Vec<String> = results
+        .iter()
+        .filter(|r| matches!(r.verdict, Verdict::Wrong { .. }))
+        .map(|r| format!("Day {:02} Part {}", r.day, r.part))
+        .collect();
+
+    if !wrong.is_empty() {
+        println!("{}Wrong:{} {}", ANSI_BOLD, ANSI_RESET, wrong.join(", "));
+    }
+
+    let failed: Vec<String> = results
+        .iter()
+        .filter_map(|r| {
+            let failure = r.failure.as_ref()?.as_str().to_uppercase();
+            Some(format!("Day {:02} Part {} ({})", r.day, r.part, failure))
+        })
+        .collect();
+
+    if !failed.is_empty() {
+        println!("{}Failed:{} {}", ANSI_BOLD, ANSI_RESET, failed.join(", "));
+    }
+
+    let still_running = limits::stopped_still_running();
+    if still_running > 0 {
+        println!(
+            "{}Still running:{} {} stopped part{}, the timings of parts after {} may be skewed",
+            ANSI_BOLD,
+            ANSI_RESET,
+            still_running,
+            if still_running == 1 { "" } else { "s" },
+            if still_running == 1 { "it" } else { "them" }
+        );
+    }
 }
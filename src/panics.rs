@@ -0,0 +1,190 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Catches panics of solvers, so a bad input fails a single part instead of the whole run.
+ * Parsers that read their input with `tracked_lines` get the offending line in the report.
+ */
+use crate::Failure;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::str::Lines;
+use std::sync::Once;
+
+thread_local! {
+    /// Set while `catch` runs a solver on this thread, so its panics are not printed.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The address and length of the line that is being parsed.
+    static CURRENT_LINE: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+    static LAST_PANIC: RefCell<Option<CaughtPanic>> = const { RefCell::new(None) };
+}
+
+/// A panic recorded by the hook, with the line being parsed at the time.
+struct CaughtPanic {
+    message: String,
+    location: String,
+    line: Option<(usize, usize)>,
+}
+
+/// Iterates over the lines of an input like [`str::lines`], and tells the harness which line is
+/// being parsed. A solver that panics while a line is current fails with that line in its report.
+///
+/// ```ignore
+/// let motions: Vec<Motion> = advent_of_code::tracked_lines(input)
+///     .map(|l| l.parse().unwrap())
+///     .collect();
+/// ```
+pub fn tracked_lines(input: &str) -> TrackedLines<'_> {
+    TrackedLines {
+        lines: input.lines(),
+    }
+}
+
+pub struct TrackedLines<'a> {
+    lines: Lines<'a>,
+}
+
+impl<'a> Iterator for TrackedLines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next();
+        CURRENT_LINE.set(line.map(|line| (line.as_ptr() as usize, line.len())));
+        line
+    }
+}
+
+impl Drop for TrackedLines<'_> {
+    // a panic after parsing is not blamed on the last line.
+    fn drop(&mut self) {
+        CURRENT_LINE.set(None);
+    }
+}
+
+/// Runs `f`, which works on `input`, and turns a panic into [`Failure::Panic`].
+/// The panic is reported by the returned failure instead of being printed.
+pub fn catch<T>(input: &str, f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_hook();
+    CURRENT_LINE.set(None);
+    CATCHING.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(false);
+
+    result.map_err(|payload| {
+        let caught = LAST_PANIC.take().unwrap_or_else(|| CaughtPanic {
+            message: payload_message(payload.as_ref()),
+            location: String::new(),
+            line: None,
+        });
+        Failure::Panic {
+            message: caught.message,
+            location: caught.location,
+            line: caught.line.and_then(|line| find_line(input, line)),
+        }
+    })
+}
+
+/// Installs a panic hook that records panics of solvers in `catch`, and passes on all others.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.try_with(Cell::get).unwrap_or(false) {
+                return default_hook(info);
+            }
+            let location = info
+                .location()
+                .map(|l| {
+                    let file = Path::new(l.file());
+                    let file = file.strip_prefix(crate::project_dir()).unwrap_or(file);
+                    format!("{}:{}:{}", file.display(), l.line(), l.column())
+                })
+                .unwrap_or_default();
+            LAST_PANIC.set(Some(CaughtPanic {
+                message: payload_message(info.payload()),
+                location,
+                line: CURRENT_LINE.get(),
+            }));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "Box<dyn Any>".to_string(),
+        },
+    }
+}
+
+/// Finds the 1-based number and the text of a line of `input`, given by its address and length.
+/// `None` if the line was parsed from another string.
+fn find_line(input: &str, (address, len): (usize, usize)) -> Option<(usize, String)> {
+    let offset = address.checked_sub(input.as_ptr() as usize)?;
+    let text = input.get(offset..offset.checked_add(len)?)?;
+    let number = input[..offset].matches('\n').count() + 1;
+    Some((number, text.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        let input = "R 4\nU x\nL 3";
+        let failure = catch(input, || {
+            tracked_lines(input)
+                .map(|l| l[2..].parse::<u32>().unwrap())
+                .sum::<u32>()
+        })
+        .unwrap_err();
+
+        let Failure::Panic {
+            message,
+            location,
+            line,
+        } = failure
+        else {
+            panic!("unexpected failure: {:?}", failure);
+        };
+        assert!(message.contains("InvalidDigit"), "{}", message);
+        assert!(location.starts_with("src/panics.rs:"), "{}", location);
+        assert_eq!(line, Some((2, "U x".to_string())));
+
+        assert_eq!(catch(input, || tracked_lines(input).count()), Ok(3));
+    }
+
+    #[test]
+    fn test_catch_after_parsing() {
+        let input = "1\n2";
+        let failure = catch(input, || {
+            let numbers: Vec<u32> = tracked_lines(input).map(|l| l.parse().unwrap()).collect();
+            if numbers.len() == 2 {
+                panic!("no solution for {} numbers", numbers.len());
+            }
+        });
+
+        assert!(matches!(
+            failure,
+            Err(Failure::Panic { message, line: None, .. }) if message == "no solution for 2 numbers"
+        ));
+    }
+
+    #[test]
+    fn test_find_line() {
+        let input = "a\nbc\nd";
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            find_line(input, (line.as_ptr() as usize, line.len())),
+            Some((2, "bc".to_string()))
+        );
+        let other = String::from("bc");
+        assert_eq!(find_line(input, (other.as_ptr() as usize, 2)), None);
+    }
+}
@@ -0,0 +1,515 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Helpers to parse puzzle inputs with errors that point at the line and column of bad input.
+ */
+use std::any::type_name;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// Why an input could not be parsed, with the 1-based line and column where known.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line, unless a more precise one is known already.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column, unless a more precise one is known already.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+// solutions `unwrap()` their parse results, so the panic message should read like the error.
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A part of an input that knows where it starts, so its errors point into the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub text: &'a str,
+    /// The 1-based line of the input that the block starts at.
+    pub first_line: usize,
+}
+
+impl<'a> Block<'a> {
+    /// Parses every line with `f`, adding the line number to its errors.
+    pub fn lines_with<T>(
+        &self,
+        mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        crate::tracked_lines(self.text)
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|e| e.at_line(self.first_line + i)))
+            .collect()
+    }
+
+    /// Parses every line with its [`FromStr`] implementation.
+    pub fn lines<T: FromStr<Err = ParseError>>(&self) -> Result<Vec<T>, ParseError> {
+        self.lines_with(str::parse)
+    }
+}
+
+/// Splits an input into its blocks of lines that are separated by blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = Block<'_>> {
+    let (mut offset, mut line) = (0, 1);
+    input
+        .trim_end()
+        .split("\n\n")
+        // more than one blank line in a row leaves newlines around a block, or empty pieces.
+        .map(|text| text.trim_start_matches('\n'))
+        .filter(|text| !text.is_empty())
+        .map(move |text| {
+            let start = text.as_ptr() as usize - input.as_ptr() as usize;
+            line += input[offset..start].matches('\n').count();
+            offset = start;
+            Block {
+                text,
+                first_line: line,
+            }
+        })
+}
+
+/// Parses every line of an input with `f`, adding the line number to its errors.
+pub fn lines_with<'a, T>(
+    input: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    Block {
+        text: input,
+        first_line: 1,
+    }
+    .lines_with(f)
+}
+
+/// Parses every line of an input with its [`FromStr`] implementation.
+///
+/// ```ignore
+/// let instructions: Vec<Instruction> = parse::lines(input).unwrap();
+/// ```
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    lines_with(input, str::parse)
+}
+
+/// Finds every integer in `s`. A `-` right before a number is its sign, unless it follows
+/// another number like in the range `3-5`.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    find_numbers(s).iter().map(Number::parse).collect()
+}
+
+/// Extracts exactly as many integers as the result has elements, from a line or a whole input.
+/// Integers are found like in [`numbers`].
+///
+/// ```ignore
+/// let (amount, from, to): (usize, usize, usize) = parse::integers("move 1 from 2 to 1")?;
+/// let [x, y]: [i64; 2] = parse::integers("x=-4, y=12")?;
+/// ```
+pub fn integers<T: FromIntegers>(s: &str) -> Result<T, ParseError> {
+    let numbers = find_numbers(s);
+    if numbers.len() != T::COUNT {
+        let message = format!(
+            "expected {} integer{}, found {}",
+            T::COUNT,
+            if T::COUNT == 1 { "" } else { "s" },
+            numbers.len()
+        );
+        return Err(match s.contains('\n') {
+            true => ParseError::new(message),
+            false => ParseError::new(format!("{} in \"{}\"", message, s)),
+        });
+    }
+    T::from_numbers(&numbers)
+}
+
+/// Arrays and tuples that [`integers`] can fill, with every element parsed on its own.
+pub trait FromIntegers: Sized {
+    const COUNT: usize;
+
+    fn from_numbers(numbers: &[Number]) -> Result<Self, ParseError>;
+}
+
+impl<T: FromStr, const N: usize> FromIntegers for [T; N] {
+    const COUNT: usize = N;
+
+    fn from_numbers(numbers: &[Number]) -> Result<Self, ParseError> {
+        let numbers: Vec<T> = numbers
+            .iter()
+            .map(Number::parse)
+            .collect::<Result<_, _>>()?;
+        match numbers.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("`integers` checks the count"),
+        }
+    }
+}
+
+macro_rules! impl_from_integers_for_tuple {
+    ($count:expr; $($element:ident),+) => {
+        impl<$($element: FromStr),+> FromIntegers for ($($element,)+) {
+            const COUNT: usize = $count;
+
+            fn from_numbers(numbers: &[Number]) -> Result<Self, ParseError> {
+                let mut numbers = numbers.iter();
+                Ok(($(numbers.next().expect("`integers` checks the count").parse::<$element>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_integers_for_tuple!(1; A);
+impl_from_integers_for_tuple!(2; A, B);
+impl_from_integers_for_tuple!(3; A, B, C);
+impl_from_integers_for_tuple!(4; A, B, C, D);
+impl_from_integers_for_tuple!(5; A, B, C, D, E);
+impl_from_integers_for_tuple!(6; A, B, C, D, E, F);
+
+/// An integer found in a text, with where it was found.
+pub struct Number<'a> {
+    text: &'a str,
+    /// The 1-based line, only known when the text has more than one line.
+    line: Option<usize>,
+    column: usize,
+}
+
+impl Number<'_> {
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let result = parse_field(self.text, self.column);
+        match self.line {
+            Some(line) => result.map_err(|e| e.at_line(line)),
+            None => result,
+        }
+    }
+}
+
+/// Every integer in `s`, with its position. Finds them in one pass, keeping track of the line
+/// and column, so that it stays linear on whole inputs.
+fn find_numbers(s: &str) -> Vec<Number<'_>> {
+    let bytes = s.as_bytes();
+    let is_multiline = s.contains('\n');
+    let mut numbers = vec![];
+    let (mut line, mut column) = (1, 1);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            match bytes[i] {
+                b'\n' => (line, column) = (line + 1, 1),
+                // counts characters, not the continuation bytes of UTF-8.
+                byte if byte & 0xC0 != 0x80 => column += 1,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(Number {
+            text: &s[start..i],
+            line: is_multiline.then_some(line),
+            column,
+        });
+        column += i - start;
+    }
+
+    numbers
+}
+
+/// Matches a line against a template like `move {} from {} to {}`, where every `{}` captures
+/// the text up to the literal text that follows it, and the last one the rest of the line.
+///
+/// ```ignore
+/// let mut fields = parse::scan(line, "move {} from {} to {}")?;
+/// let (amount, from, to): (usize, usize, usize) = (fields.parse()?, fields.parse()?, fields.parse()?);
+/// ```
+pub fn scan<'a>(line: &'a str, template: &str) -> Result<Fields<'a>, ParseError> {
+    let expected = |literal: &str, offset: usize| {
+        ParseError::new(format!(
+            "expected \"{}\", found \"{}\"",
+            literal,
+            &line[offset..]
+        ))
+        .at_column(column(line, offset))
+    };
+
+    let mut literals = template.split("{}").peekable();
+    let prefix = literals.next().unwrap_or_default();
+    let mut rest = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| expected(prefix, 0))?;
+    let mut fields = vec![];
+
+    while let Some(literal) = literals.next() {
+        let offset = line.len() - rest.len();
+        let is_last = literals.peek().is_none();
+        assert!(
+            is_last || !literal.is_empty(),
+            "the fields of \"{}\" need literal text in between",
+            template
+        );
+
+        let len = match (literal.is_empty(), is_last) {
+            (true, _) => rest.len(),
+            (false, true) => rest
+                .strip_suffix(literal)
+                .ok_or_else(|| expected(literal, offset))?
+                .len(),
+            (false, false) => rest
+                .find(literal)
+                .ok_or_else(|| expected(literal, offset))?,
+        };
+
+        fields.push((column(line, offset), &rest[..len]));
+        rest = &rest[len + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        let offset = line.len() - rest.len();
+        return Err(
+            ParseError::new(format!("unexpected \"{}\"", rest)).at_column(column(line, offset))
+        );
+    }
+
+    Ok(Fields {
+        fields: fields.into_iter(),
+    })
+}
+
+/// The captured fields of [`scan`], in order.
+pub struct Fields<'a> {
+    fields: std::vec::IntoIter<(usize, &'a str)>,
+}
+
+impl<'a> Fields<'a> {
+    /// Parses the next field.
+    pub fn parse<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let (column, field) = self.next_str_with_column()?;
+        parse_field(field, column)
+    }
+
+    /// The text of the next field.
+    pub fn next_str(&mut self) -> Result<&'a str, ParseError> {
+        self.next_str_with_column().map(|(_, field)| field)
+    }
+
+    fn next_str_with_column(&mut self) -> Result<(usize, &'a str), ParseError> {
+        self.fields
+            .next()
+            .ok_or_else(|| ParseError::new("the template has no more fields"))
+    }
+}
+
+/// Parses a field that starts at `column`.
+fn parse_field<T: FromStr>(field: &str, column: usize) -> Result<T, ParseError> {
+    field.parse().map_err(|_| {
+        let type_name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+        ParseError::new(format!("expected {}, found \"{}\"", type_name, field)).at_column(column)
+    })
+}
+
+/// The 1-based column of a byte offset.
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = ParseError::new("expected u32, found \"x\"")
+            .at_column(5)
+            .at_line(3)
+            .at_line(1);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: expected u32, found \"x\""
+        );
+        assert_eq!(format!("{:?}", error), error.to_string());
+        assert_eq!(ParseError::new("empty").to_string(), "empty");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1\n2\n\n3\n\n\n4\n5\n";
+        let blocks: Vec<Block> = blocks(input).collect();
+        assert_eq!(
+            blocks,
+            [
+                Block {
+                    text: "1\n2",
+                    first_line: 1
+                },
+                Block {
+                    text: "3",
+                    first_line: 4
+                },
+                Block {
+                    text: "4\n5",
+                    first_line: 7
+                },
+            ]
+        );
+
+        let error = blocks[2].lines::<Sections>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 1: expected \"{\", found \"4\""
+        );
+
+        // an odd number of blank lines in a row splits off an empty piece.
+        let first_lines: Vec<(&str, usize)> = super::blocks("a\n\n\n\nb\n\n\n\n\nc")
+            .map(|block| (block.text, block.first_line))
+            .collect();
+        assert_eq!(first_lines, [("a", 1), ("b", 5), ("c", 10)]);
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines::<Sections>("{2-4}\n{6-8}"),
+            Ok(vec![Sections(2, 4), Sections(6, 8)])
+        );
+        assert_eq!(
+            lines::<Sections>("{2-4}\n{6-x}").unwrap_err().to_string(),
+            "line 2, column 4: expected u32, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<i32>("move 2 from -3 to 4"), Ok(vec![2, -3, 4]));
+        assert_eq!(numbers::<u32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(numbers::<i32>("x=-12, y=7"), Ok(vec![-12, 7]));
+        assert_eq!(numbers::<u32>("none"), Ok(vec![]));
+        assert_eq!(
+            numbers::<u8>("a 300").unwrap_err().to_string(),
+            "column 3: expected u8, found \"300\""
+        );
+        assert_eq!(
+            numbers::<i8>("1\n2 3\nx-4 y=256").unwrap_err().to_string(),
+            "line 3, column 7: expected i8, found \"256\""
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let (amount, from, to): (usize, usize, u8) = integers("move 12 from 2 to 3").unwrap();
+        assert_eq!((amount, from, to), (12, 2, 3));
+        assert_eq!(integers("x=-4, y=12"), Ok([-4, 12]));
+        assert_eq!(integers::<[u32; 4]>("2-4,6-8"), Ok([2, 4, 6, 8]));
+        assert_eq!(integers::<[u32; 2]>("1\n2\n"), Ok([1, 2]));
+
+        assert_eq!(
+            integers::<(usize, usize, usize)>("move 1 from 2")
+                .unwrap_err()
+                .to_string(),
+            "expected 3 integers, found 2 in \"move 1 from 2\""
+        );
+        assert_eq!(
+            integers::<[u32; 1]>("1\n2").unwrap_err().to_string(),
+            "expected 1 integer, found 2"
+        );
+        assert_eq!(
+            integers::<(u32, u32)>("7\nx -3").unwrap_err().to_string(),
+            "line 2, column 3: expected u32, found \"-3\""
+        );
+        assert_eq!(
+            integers::<[u8; 5]>("1 2\n3\n\nä: 4, 300")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 7: expected u8, found \"300\""
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        let mut fields = scan("move 12 from 2 to 3", "move {} from {} to {}").unwrap();
+        assert_eq!(fields.parse::<u32>(), Ok(12));
+        assert_eq!(fields.next_str(), Ok("2"));
+        assert_eq!(fields.parse::<u32>(), Ok(3));
+        assert!(fields.parse::<u32>().is_err());
+
+        let error = |line: &str| {
+            scan(line, "move {} from {} to {}")
+                .and_then(|mut f| f.parse::<u32>())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("mvoe 1 from 2 to 3"),
+            "column 1: expected \"move \", found \"mvoe 1 from 2 to 3\""
+        );
+        assert_eq!(
+            error("move 1 to 3"),
+            "column 6: expected \" from \", found \"1 to 3\""
+        );
+        assert_eq!(
+            error("move x from 2 to 3"),
+            "column 6: expected u32, found \"x\""
+        );
+
+        assert_eq!(
+            scan("addx 3 4", "addx {} {}.").err().map(|e| e.to_string()),
+            Some("column 8: expected \".\", found \"4\"".to_string())
+        );
+        assert_eq!(
+            scan("noop 1", "noop").err().map(|e| e.to_string()),
+            Some("column 5: unexpected \" 1\"".to_string())
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Sections(u32, u32);
+
+    impl FromStr for Sections {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut fields = scan(s, "{{}-{}}")?;
+            Ok(Sections(fields.parse()?, fields.parse()?))
+        }
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Helpers for the puzzle page. They only handle the small subset of HTML that puzzle
+ * descriptions use: headings, paragraphs, emphasis, code, lists and links.
+ */
+use crate::config;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// The path of a day's puzzle description, e.g. `src/puzzles/2022/07.md`.
+pub fn get_path(year: u16, day: u8) -> PathBuf {
+    crate::project_dir()
+        .join(config::get().folder("puzzles"))
+        .join(year.to_string())
+        .join(format!("{:02}.md", day))
+}
+
+/// Converts a puzzle page to Markdown and writes it to `path`, creating its folder if needed.
+pub fn write_markdown(path: &Path, html: &str, base_url: &str) -> io::Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, to_markdown(html, base_url))
+}
+
+/// Returns the inner HTML of every `<article class="day-desc">`, i.e. one per unlocked part.
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        rest = &rest[start + "<article class=\"day-desc\">".len()..];
+        let end = rest.find("</article>").unwrap_or(rest.len());
+        articles.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// Returns the first `<pre><code>` block of part one, which usually is the example input.
+pub fn extract_example(html: &str) -> Option<String> {
+    let article = extract_articles(html).into_iter().next()?;
+    let start = article.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + article[start..].find("</code></pre>")?;
+    Some(decode_entities(&strip_tags(&article[start..end])))
+}
+
+/// Guesses the example answers of both parts: puzzles highlight them as `<code><em>...</em></code>`,
+/// usually as the last highlighted value of a part's description.
+pub fn extract_example_answers(html: &str) -> [Option<String>; 2] {
+    let mut articles = extract_articles(html).into_iter().map(|article| {
+        article
+            .rmatch_indices("<code><em>")
+            .next()
+            .and_then(|(start, tag)| {
+                let start = start + tag.len();
+                let end = start + article[start..].find("</em></code>")?;
+                Some(decode_entities(&article[start..end]))
+            })
+    });
+
+    [articles.next().flatten(), articles.next().flatten()]
+}
+
+/// Converts the puzzle articles of a page to Markdown. Root-relative links are prefixed with `base_url`.
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    extract_articles(html)
+        .iter()
+        .map(|article| article_to_markdown(article, base_url))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+fn article_to_markdown(html: &str, base_url: &str) -> String {
+    // emphasis does not render inside inline code, swap it to the outside.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut hrefs: Vec<Option<String>> = vec![];
+    let mut rest = html.as_str();
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+        rest = &rest[tag_start..];
+        let tag_end = rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+        let tag = &rest[1..tag_end - 1];
+        rest = &rest[tag_end..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => out += "## ",
+            ("h2", true) | ("p", true) => out += "\n\n",
+            ("em", _) if !in_pre => out += "*",
+            ("code", _) if !in_pre => out += "`",
+            ("pre", false) => {
+                out += "```\n";
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out += "\n";
+                }
+                out += "```\n\n";
+                in_pre = false;
+            }
+            ("li", false) => out += "- ",
+            ("li", true) => out += "\n",
+            ("ul", true) => out += "\n",
+            ("br", _) => out += "\n",
+            ("a", false) => {
+                let href = attribute(tag, "href").map(|href| {
+                    if href.starts_with('/') {
+                        format!("{}{}", base_url.trim_end_matches('/'), href)
+                    } else {
+                        href.to_string()
+                    }
+                });
+                if href.is_some() {
+                    out += "[";
+                }
+                hrefs.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = hrefs.pop() {
+                    out += &format!("]({})", href);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    collapse_blank_lines(out.trim())
+}
+
+/// Appends a text node. Outside of `<pre>`, line breaks are only formatting and become spaces.
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+    if in_pre {
+        *out += &text;
+    } else if !(text.trim().is_empty() && text.contains('\n')) {
+        *out += &text.replace('\n', " ");
+    }
+}
+
+pub fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut newlines = 0;
+    for c in text.chars() {
+        if c == '\n' {
+            newlines += 1;
+            if newlines > 2 {
+                continue;
+            }
+        } else {
+            newlines = 0;
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be <em>too</em> overgrown.</p>
+<p>For example, suppose the Elves <a href="/2022/about">finish</a> writing:</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul>
+<li>The first Elf has <code>6000</code> &lt;Calories&gt;.</li>
+<li>The <a href="https://example.com" target="_blank">second</a> Elf.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>By the time you calculate the answer...</p>
+<p>In the example above, this is <code><em>45000</em></code> Calories.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_articles() {
+        let articles = extract_articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert!(articles[0].starts_with("<h2>--- Day 1"));
+        assert!(articles[1].starts_with("<h2 id=\"part2\">--- Part Two"));
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE, "https://adventofcode.com"),
+            "## --- Day 1: Calorie Counting ---
+
+The jungle must be *too* overgrown.
+
+For example, suppose the Elves [finish](https://adventofcode.com/2022/about) writing:
+
+```
+1000
+2000
+```
+
+- The first Elf has `6000` <Calories>.
+- The [second](https://example.com) Elf.
+
+## --- Part Two ---
+
+By the time you calculate the answer...
+
+In the example above, this is *`45000`* Calories.
+"
+        );
+    }
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(extract_example(PAGE), Some("1000\n2000\n".to_string()));
+        assert_eq!(extract_example("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_extract_example_answers() {
+        assert_eq!(
+            extract_example_answers(PAGE),
+            [None, Some("45000".to_string())]
+        );
+        assert_eq!(
+            extract_example_answers(
+                r#"<article class="day-desc"><p><code><em>1</em></code>, then <code><em>CMZ</em></code>.</p></article>"#
+            ),
+            [Some("CMZ".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_no_articles() {
+        assert_eq!(to_markdown("<main></main>", ""), "\n");
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Renders the progress table that `cargo readme` writes into README.md.
+ */
+use crate::{PartResult, Verdict};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub const SECTION_START: &str = "<!--- advent_of_code table start --->";
+pub const SECTION_END: &str = "<!--- advent_of_code table end --->";
+
+/// Renders a Markdown table with one row per day: its stars, the verdicts of its answers and the
+/// median time of each part. Parts that failed show the failure, e.g. `TIMEOUT`, and parts that
+/// did not run show `–`.
+pub fn render_table(year: u16, results: &[PartResult]) -> String {
+    let mut table = String::from(
+        "| Day | Solved | Answers | Part 1 | Part 2 |\n| :--- | :---: | :---: | ---: | ---: |\n",
+    );
+
+    let mut days: BTreeMap<u8, [Option<&PartResult>; 2]> = BTreeMap::new();
+    for result in results {
+        days.entry(result.day).or_default()[result.part as usize - 1] = Some(result);
+    }
+
+    for (day, parts) in days {
+        let stars = parts.iter().flatten().filter(|part| has_star(part)).count();
+        let answers: Vec<String> = parts
+            .iter()
+            .map(|part| part.map_or_else(|| "–".to_string(), verdict_symbol))
+            .collect();
+        let timings: Vec<String> = parts
+            .iter()
+            .map(|part| part.map_or_else(|| "–".to_string(), format_timing))
+            .collect();
+
+        table += &format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} | {} |\n",
+            day,
+            year,
+            day,
+            "⭐".repeat(stars) + &"☆".repeat(2 - stars),
+            answers.join(" "),
+            timings.join(" | ")
+        );
+    }
+
+    let stars = results.iter().filter(|part| has_star(part)).count();
+    let total: Duration = results
+        .iter()
+        .filter(|part| has_star(part))
+        .map(|part| part.elapsed)
+        .sum();
+
+    table += &format!("| **Total** | {} ⭐ | | {:.2?} | |\n", stars, total);
+    table
+}
+
+/// Whether a part counts as a star: it has an answer that is not known to be wrong.
+pub fn has_star(part: &PartResult) -> bool {
+    part.is_solved() && !matches!(part.verdict, Verdict::Wrong { .. })
+}
+
+fn verdict_symbol(part: &PartResult) -> String {
+    if let Some(failure) = &part.failure {
+        return failure.as_str().to_uppercase();
+    }
+    let symbol = match (&part.verdict, part.is_solved()) {
+        (_, false) => "–",
+        (Verdict::Correct, _) => "✅",
+        (Verdict::Wrong { .. }, _) => "❌",
+        (Verdict::Unknown, _) => "❔",
+    };
+    symbol.to_string()
+}
+
+fn format_timing(part: &PartResult) -> String {
+    match &part.failure {
+        Some(failure) => failure.as_str().to_uppercase(),
+        None if part.is_solved() => format!("{:.2?}", part.elapsed),
+        None => "–".to_string(),
+    }
+}
+
+/// Replaces the content between the section markers of a README.
+pub fn replace_section(readme: &str, content: &str) -> Result<String, String> {
+    let missing = |marker: &str| format!("could not find the marker `{}`", marker);
+    let start = readme
+        .find(SECTION_START)
+        .ok_or_else(|| missing(SECTION_START))?
+        + SECTION_START.len();
+    let end = start
+        + readme[start..]
+            .find(SECTION_END)
+            .ok_or_else(|| missing(SECTION_END))?;
+
+    Ok(format!(
+        "{}\n\n{}\n\n{}",
+        &readme[..start],
+        content.trim_end(),
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_part, Failure, PartResult, Timing};
+
+    #[test]
+    fn test_render_table() {
+        let answers = [Some("3".to_string()), Some("4".to_string())];
+        let mut results = vec![
+            run_part(2022, 1, 1, |input| Some(input.len()), "abc", Timing::Once),
+            run_part(
+                2022,
+                1,
+                2,
+                |input| Some(input.len() + 1),
+                "abc",
+                Timing::Once,
+            ),
+            run_part(2022, 2, 1, |input| Some(input.len()), "abc", Timing::Once),
+            run_part(2022, 2, 2, |_| None::<u32>, "abc", Timing::Once),
+            PartResult::failed(2022, 3, 1, Failure::Timeout(Duration::from_secs(1))),
+            PartResult::failed(2022, 3, 2, Failure::OutOfMemory(1 << 20)),
+            // a day with one part, listed out of order.
+            run_part(2022, 5, 2, |input| Some(input.len()), "abc", Timing::Once),
+            run_part(2022, 4, 1, |input| Some(input.len()), "abc", Timing::Once),
+        ];
+        for result in results.iter_mut() {
+            result.elapsed = Duration::from_micros(result.day as u64 * 10 + result.part as u64);
+            match result.day {
+                1 => result.check(&answers),
+                4 => result.check(&[Some("5".to_string()), None]),
+                _ => {}
+            }
+        }
+
+        assert_eq!(
+            render_table(2022, &results),
+            "| Day | Solved | Answers | Part 1 | Part 2 |
+| :--- | :---: | :---: | ---: | ---: |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐⭐ | ✅ ✅ | 11.00µs | 12.00µs |
+| [Day 2](https://adventofcode.com/2022/day/2) | ⭐☆ | ❔ – | 21.00µs | – |
+| [Day 3](https://adventofcode.com/2022/day/3) | ☆☆ | TIMEOUT OOM | TIMEOUT | OOM |
+| [Day 4](https://adventofcode.com/2022/day/4) | ☆☆ | ❌ – | 41.00µs | – |
+| [Day 5](https://adventofcode.com/2022/day/5) | ⭐☆ | – ❔ | – | 52.00µs |
+| **Total** | 4 ⭐ | | 96.00µs | |
+"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# AoC\n{}\nold\n{}\nmore", SECTION_START, SECTION_END);
+        assert_eq!(
+            replace_section(&readme, "new\n").unwrap(),
+            format!("# AoC\n{}\n\nnew\n\n{}\nmore", SECTION_START, SECTION_END)
+        );
+        assert_eq!(
+            replace_section(&replace_section(&readme, "new").unwrap(), "new").unwrap(),
+            replace_section(&readme, "new").unwrap()
+        );
+        assert!(replace_section("# AoC", "new").is_err());
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * The registry of solutions, for the binaries that run them: `main.rs`, `solve`, `readme` and
+ * `submit`, and the regression test. It is not part of the library, so that the other commands
+ * still build while a day does not compile.
+ */
+use advent_of_code::Solution;
+
+// the `yYYYY` solution modules and `SOLUTIONS`, generated by `build.rs` from `src/yYYYY/NN.rs`.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// Solutions that register more than their parts, in place of their entry in [`SOLUTIONS`].
+const REGISTERED: &[Solution] = &[advent_of_code::solution!(2022, 9, y2022::day09, visualize)];
+
+/// Looks up the solution of a day in [`REGISTERED`] and [`SOLUTIONS`].
+pub fn find_solution(year: u16, day: u8) -> Option<&'static Solution> {
+    REGISTERED
+        .iter()
+        .chain(SOLUTIONS)
+        .find(|s| s.year == year && s.day == day)
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::upper_case_acronyms)]
+
 #[derive(PartialEq)]
 enum Shape {
     ROCK,
@@ -18,8 +20,7 @@ enum Result {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let total_points: u32 = input
-        .lines()
+    let total_points: u32 = advent_of_code::tracked_lines(input)
         .map(|r| {
             let (opponent, me) = r.split_once(' ').unwrap();
             get_result(get_shape(opponent), get_shape(me))
@@ -30,8 +31,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let total_points: u32 = input
-        .lines()
+    let total_points: u32 = advent_of_code::tracked_lines(input)
         .map(|r| {
             let (opponent, me) = r.split_once(' ').unwrap();
             follow_strategy(get_shape(opponent), get_strategy(me))
@@ -111,25 +111,19 @@ fn use_losing(shape: Shape) -> Shape {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2).unwrap();
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2).unwrap();
         assert_eq!(part_two(&input), Some(12));
     }
 }
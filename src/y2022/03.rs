@@ -1,3 +1,5 @@
+#![allow(clippy::redundant_closure)]
+
 use itertools::Itertools;
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -45,25 +47,19 @@ fn get_priority(char: char) -> u32 {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3).unwrap();
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3).unwrap();
         assert_eq!(part_two(&input), Some(70));
     }
 }
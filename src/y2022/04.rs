@@ -0,0 +1,52 @@
+use advent_of_code::parse::{self, ParseError};
+
+type Sections = (u32, u32);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let fully_containing = parse_pairs(input)
+        .into_iter()
+        .filter(|&((min_a, max_a), (min_b, max_b))| {
+            (min_a >= min_b && max_a <= max_b) || (min_b >= min_a && max_b <= max_a)
+        })
+        .count();
+
+    Some(fully_containing as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let overlapping = parse_pairs(input)
+        .into_iter()
+        .filter(|&((min_a, max_a), (min_b, max_b))| !((max_a < min_b) || (max_b < min_a)))
+        .count();
+
+    Some(overlapping as u32)
+}
+
+fn parse_pairs(input: &str) -> Vec<(Sections, Sections)> {
+    parse::lines_with(input, parse_pair).unwrap()
+}
+
+fn parse_pair(line: &str) -> Result<(Sections, Sections), ParseError> {
+    let mut fields = parse::scan(line, "{}-{},{}-{}")?;
+    Ok((
+        (fields.parse()?, fields.parse()?),
+        (fields.parse()?, fields.parse()?),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_two(&input), Some(4));
+    }
+}
@@ -1,3 +1,4 @@
+use advent_of_code::parse::{self, ParseError};
 use itertools::Itertools;
 use std::str::FromStr;
 
@@ -9,29 +10,23 @@ struct Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // example: move[0] 2[1] from[2] 2[3] to[4] 3[5]
-        let parts: Vec<_> = s.split_ascii_whitespace().collect();
-
-        let from = parts[3].parse::<usize>().unwrap();
-        let to = parts[5].parse::<usize>().unwrap();
+        let (amount, from, to): (usize, usize, usize) = parse::integers(s)?;
 
         Ok(Instruction {
             from: from - 1,
             to: to - 1,
-            amount: parts[1].parse().unwrap(),
+            amount,
         })
     }
 }
 
 pub fn part_one(input: &str) -> Option<String> {
-    let (stacks, instructions) = input.split_once("\n\n").unwrap();
-    let mut supplies = parse_stacks(stacks);
+    let (mut supplies, instructions) = parse_input(input);
 
-    instructions.lines().for_each(|l| {
-        let i = Instruction::from_str(l).unwrap();
+    instructions.iter().for_each(|i| {
         (0..i.amount).for_each(|_n| {
             let cargo = supplies[i.from].pop().unwrap();
             supplies[i.to].push(cargo);
@@ -42,11 +37,9 @@ pub fn part_one(input: &str) -> Option<String> {
 }
 
 pub fn part_two(input: &str) -> Option<String> {
-    let (stacks, instructions) = input.split_once("\n\n").unwrap();
-    let mut supplies = parse_stacks(stacks);
+    let (mut supplies, instructions) = parse_input(input);
 
-    instructions.lines().for_each(|l| {
-        let i = Instruction::from_str(l).unwrap();
+    instructions.iter().for_each(|i| {
         let index = supplies[i.from].len() - i.amount;
         let cargo = supplies[i.from].split_off(index);
         supplies[i.to].extend(cargo);
@@ -55,6 +48,12 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(get_top_crates(supplies))
 }
 
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<Instruction>) {
+    let mut blocks = parse::blocks(input);
+    let (stacks, instructions) = (blocks.next().unwrap(), blocks.next().unwrap());
+    (parse_stacks(stacks.text), instructions.lines().unwrap())
+}
+
 fn parse_stacks(input: &str) -> Vec<Vec<char>> {
     let mut stack_iter = input.lines().rev();
     let no_stacks = stack_iter
@@ -80,12 +79,6 @@ fn get_top_crates(supplies: Vec<Vec<char>>) -> String {
     supplies.iter().filter_map(|stack| stack.last()).join("")
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 fn get_stacks(row: &str) -> Vec<Option<char>> {
     let mut stack: Vec<Option<char>> = vec![];
     row.chars().skip(1).enumerate().for_each(|(ix, c)| {
@@ -106,13 +99,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5).unwrap();
         assert_eq!(part_one(&input), Some("CMZ".to_owned()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5).unwrap();
         assert_eq!(part_two(&input), Some("MCD".to_owned()));
     }
 
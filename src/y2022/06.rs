@@ -0,0 +1,66 @@
+#![allow(clippy::char_indices_as_byte_indices)]
+
+use std::collections::HashSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    find_message_start(input, 4)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    find_message_start(input, 14)
+}
+
+fn find_message_start(input: &str, length: usize) -> Option<u32> {
+    for (i, _c) in input.chars().enumerate() {
+        let mut unique = HashSet::with_capacity(length);
+        if i >= (length - 1) {
+            let is_unique = input[i - (length - 1)..=i]
+                .chars()
+                .all(|c| unique.insert(c));
+            if is_unique {
+                return Some(i as u32 + 1);
+            }
+
+            unique.clear();
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::check_examples(
+            2022,
+            6,
+            part_one,
+            &[
+                ("a", Some(7)),
+                ("b", Some(5)),
+                ("c", Some(6)),
+                ("d", Some(10)),
+                ("e", Some(11)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::check_examples(
+            2022,
+            6,
+            part_two,
+            &[
+                ("a", Some(19)),
+                ("b", Some(23)),
+                ("c", Some(23)),
+                ("d", Some(29)),
+                ("e", Some(26)),
+            ],
+        );
+    }
+}
@@ -1,3 +1,6 @@
+#![allow(clippy::single_char_add_str)]
+
+use advent_of_code::parse::{self, ParseError};
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -19,30 +22,29 @@ struct Item {
 }
 
 impl FromStr for Item {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (id, name) = s.split_once(' ').unwrap();
-
-        return if id == "dir" {
-            Ok(Self {
+        if let Some(name) = s.strip_prefix("dir ") {
+            return Ok(Self {
                 file_type: FileType::Directory,
                 path: name.to_owned(),
                 size: 0,
                 parent: None,
                 depth: 0,
                 children_dirs: vec![],
-            })
-        } else {
-            Ok(Self {
-                file_type: FileType::File,
-                path: name.to_owned(),
-                size: id.parse::<usize>().unwrap(),
-                parent: None,
-                depth: 0,
-                children_dirs: vec![],
-            })
-        };
+            });
+        }
+
+        let mut fields = parse::scan(s, "{} {}")?;
+        Ok(Self {
+            file_type: FileType::File,
+            size: fields.parse()?,
+            path: fields.parse()?,
+            parent: None,
+            depth: 0,
+            children_dirs: vec![],
+        })
     }
 }
 
@@ -52,20 +54,15 @@ enum Command {
 }
 
 impl FromStr for Command {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split_whitespace();
-
-        parts.next().unwrap();
-        let command_type = parts.next().unwrap();
-        let argument = parts.next();
-
-        match command_type {
-            "ls" => Ok(Command::LS),
-            "cd" => Ok(Command::CD(argument.unwrap().to_string())),
-            _ => unreachable!(),
+        if s == "$ ls" {
+            return Ok(Command::LS);
         }
+
+        let mut fields = parse::scan(s, "$ cd {}")?;
+        Ok(Command::CD(fields.parse()?))
     }
 }
 
@@ -118,7 +115,7 @@ fn parse_file_system(input: &str) -> HashMap<String, Item> {
         },
     );
 
-    for line in input.lines().skip(1) {
+    for line in advent_of_code::tracked_lines(input).skip(1) {
         if !collecting || line.starts_with('$') {
             let command = Command::from_str(line).unwrap();
             match command {
@@ -192,25 +189,19 @@ fn parse_file_system(input: &str) -> HashMap<String, Item> {
     totals
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7).unwrap();
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_dir_size() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7).unwrap();
         let file_system = parse_file_system(&input);
         assert_eq!(file_system.get("/a/e").unwrap().size, 584);
         assert_eq!(file_system.get("/a").unwrap().size, 94853);
@@ -220,7 +211,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7).unwrap();
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -0,0 +1,63 @@
+use advent_of_code::helpers::{Grid, Point, Position, DIRECTIONS_4};
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_trees(input);
+
+    // trees on the edge see out in the direction their ray is empty.
+    let num_visible_trees = grid
+        .positions()
+        .filter(|&tree| {
+            DIRECTIONS_4
+                .into_iter()
+                .any(|direction| grid.ray(tree, direction).all(|other| grid[other] < grid[tree]))
+        })
+        .count();
+
+    Some(num_visible_trees as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_trees(input);
+
+    grid.positions()
+        .map(|tree| {
+            DIRECTIONS_4
+                .into_iter()
+                .map(|direction| get_num_visible(&grid, tree, direction))
+                .product()
+        })
+        .max()
+}
+
+fn parse_trees(input: &str) -> Grid<u32> {
+    Grid::parse(input, |t| t.to_digit(10).unwrap()).unwrap()
+}
+
+fn get_num_visible(grid: &Grid<u32>, tree: Position, direction: Point) -> u32 {
+    let mut count = 0;
+    for other in grid.ray(tree, direction) {
+        count += 1;
+        if grid[other] >= grid[tree] {
+            break;
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
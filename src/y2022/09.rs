@@ -0,0 +1,151 @@
+use advent_of_code::helpers::{parse_move, Grid, Point};
+use std::collections::HashSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(count_tail_positions(input, 2) as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(count_tail_positions(input, 10) as u32)
+}
+
+pub fn visualize(input: &str) {
+    let (frames, visited) = record(input, 10);
+
+    let corners = frames.iter().flat_map(|(_, knots)| knots).chain(&visited);
+    let min = corners.clone().fold(Point::ZERO, |min, p| {
+        Point::new(min.x.min(p.x), min.y.min(p.y))
+    });
+    let max = corners.fold(Point::ZERO, |max, p| {
+        Point::new(max.x.max(p.x), max.y.max(p.y))
+    });
+
+    for (motion, knots) in &frames {
+        println!("== {} ==\n\n{}\n", motion, render_knots(knots, min, max));
+    }
+    println!("== Visited by the tail ==\n\n{}", render_visited(&visited, min, max));
+}
+
+struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    fn new(num_knots: usize) -> Self {
+        Rope {
+            knots: vec![Point::ZERO; num_knots],
+        }
+    }
+
+    fn step(&mut self, direction: Point) {
+        let knots = &mut self.knots;
+        knots[0] += direction;
+
+        // a knot that is no longer touching the one before it moves one step towards it.
+        for i in 1..knots.len() {
+            if knots[i].chebyshev_distance(knots[i - 1]) > 1 {
+                let step = (knots[i - 1] - knots[i]).signum();
+                knots[i] += step;
+            }
+        }
+    }
+
+    fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+}
+
+fn count_tail_positions(input: &str, num_knots: usize) -> usize {
+    simulate(input, num_knots, |_, _| {}).len()
+}
+
+fn simulate<'a>(
+    input: &'a str,
+    num_knots: usize,
+    mut on_motion: impl FnMut(&'a str, &[Point]),
+) -> HashSet<Point> {
+    let mut rope = Rope::new(num_knots);
+    let mut visited = HashSet::from([Point::ZERO]);
+
+    for line in advent_of_code::tracked_lines(input) {
+        let (direction, steps) = parse_move(line).unwrap();
+        for _step in 0..steps {
+            rope.step(direction);
+            visited.insert(rope.tail());
+        }
+        on_motion(line, &rope.knots);
+    }
+
+    visited
+}
+
+fn record(input: &str, num_knots: usize) -> (Vec<(&str, Vec<Point>)>, HashSet<Point>) {
+    let mut frames = vec![];
+    let visited = simulate(input, num_knots, |motion, knots| {
+        frames.push((motion, knots.to_vec()))
+    });
+    (frames, visited)
+}
+
+fn render(min: Point, max: Point, cells: impl IntoIterator<Item = (Point, char)>) -> Grid<char> {
+    let width = (max.x - min.x) as usize + 1;
+    let height = (max.y - min.y) as usize + 1;
+    let mut grid = Grid::new(width, height, '.');
+    for (point, c) in cells {
+        grid[((point.x - min.x) as usize, (point.y - min.y) as usize)] = c;
+    }
+    grid
+}
+
+fn render_knots(knots: &[Point], min: Point, max: Point) -> Grid<char> {
+    let label = |i: usize| match i {
+        0 => 'H',
+        1 if knots.len() == 2 => 'T',
+        _ => char::from_digit(i as u32, 36).unwrap_or('#'),
+    };
+    let knots = knots.iter().enumerate().rev().map(|(i, &knot)| (knot, label(i)));
+    render(min, max, [(Point::ZERO, 's')].into_iter().chain(knots))
+}
+
+fn render_visited(visited: &HashSet<Point>, min: Point, max: Point) -> Grid<char> {
+    let cells = visited.iter().map(|&point| (point, '#'));
+    render(min, max, cells.chain([(Point::ZERO, 's')]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::check_examples(2022, 9, part_one, &[("a", Some(13))]);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::check_examples(2022, 9, part_two, &[("a", Some(1)), ("b", Some(36))]);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = advent_of_code::read_example(2022, 9, "a").unwrap();
+        let (min, max) = (Point::new(0, -4), Point::new(5, 0));
+
+        let (frames, _) = record(&input, 10);
+        assert_eq!(frames[0].0, "R 4");
+        assert_eq!(
+            render_knots(&frames[0].1, min, max).to_string(),
+            "......\n......\n......\n......\n4321H."
+        );
+
+        let (frames, visited) = record(&input, 2);
+        assert_eq!(
+            render_knots(&frames[1].1, min, max).to_string(),
+            "....H.\n....T.\n......\n......\ns....."
+        );
+        assert_eq!(
+            render_visited(&visited, min, max).to_string(),
+            "..##..\n...##.\n.####.\n....#.\ns###.."
+        );
+    }
+}
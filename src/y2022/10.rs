@@ -1,3 +1,6 @@
+#![allow(clippy::needless_range_loop, clippy::unnecessary_cast)]
+
+use advent_of_code::parse::{self, ParseError};
 use std::str::FromStr;
 
 const WIDTH: usize = 40;
@@ -9,17 +12,15 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts_iter = s.split_whitespace();
-        let instruction = parts_iter.next().unwrap();
-
-        Ok(match instruction {
-            "noop" => Self::Noop,
-            "addx" => Self::Add(parts_iter.next().unwrap().parse().unwrap()),
-            _ => unreachable!(),
-        })
+        if s == "noop" {
+            return Ok(Self::Noop);
+        }
+
+        let mut fields = parse::scan(s, "addx {}")?;
+        Ok(Self::Add(fields.parse()?))
     }
 }
 
@@ -104,8 +105,7 @@ fn print_crt(crt: &[[char; 40]]) -> String {
 
 fn parse_instructions(input: &str) -> Vec<Option<i32>> {
     let mut instruction_buffer: Vec<Option<i32>> = vec![];
-    for instruction in input.lines() {
-        let instruction = Instruction::from_str(instruction).unwrap();
+    for instruction in parse::lines(input).unwrap() {
         match instruction {
             Instruction::Noop => instruction_buffer.push(None),
             Instruction::Add(value) => {
@@ -118,25 +118,19 @@ fn parse_instructions(input: &str) -> Vec<Option<i32>> {
     instruction_buffer
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10).unwrap();
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10).unwrap();
 
         let image = "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Runs every day that has an input file and recorded answers, and fails on any wrong answer.
+ * Days without an input or answers are skipped, so this passes without the private inputs.
+ */
+use advent_of_code::limits::Limits;
+use advent_of_code::{ReadError, Timing, Verdict};
+use solutions::SOLUTIONS;
+use std::process;
+use std::sync::Arc;
+
+// `find_solution` is only used by the binaries, and without a test harness the days' `tests`
+// modules are compiled but their `#[test]` functions are not, leaving their imports unused.
+#[allow(dead_code, unused_imports)]
+#[path = "../src/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+
+    println!("\nrunning regression tests for {} days", SOLUTIONS.len());
+
+    for solution in SOLUTIONS {
+        let (year, day) = (solution.year, solution.day);
+
+        let input = match advent_of_code::read_file("inputs", year, day) {
+            Ok(input) => Arc::from(input),
+            Err(ReadError::NotFound(_)) => {
+                println!("{} day {:02} ... skipped (no input)", year, day);
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{} day {:02} ... FAILED ({})", year, day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let answers = advent_of_code::read_answers(year, day);
+        if answers.iter().all(Option::is_none) {
+            println!("{} day {:02} ... skipped (no answers)", year, day);
+            skipped += 1;
+            continue;
+        }
+
+        for (part, expected) in [1, 2].into_iter().zip(answers.iter()) {
+            if expected.is_none() {
+                continue;
+            }
+
+            // a panicking part fails on its own, the other days still run.
+            let mut result = solution.run(part, &input, Timing::Once, Limits::default());
+            result.check(&answers);
+
+            match (&result.failure, &result.verdict) {
+                (Some(failure), _) => {
+                    println!(
+                        "{} day {:02} part {} ... FAILED ({})",
+                        year, day, part, failure
+                    );
+                    failed += 1;
+                }
+                (None, Verdict::Correct) => {
+                    println!("{} day {:02} part {} ... ok", year, day, result.part);
+                    passed += 1;
+                }
+                _ => {
+                    println!(
+                        "{} day {:02} part {} ... FAILED (expected: {:?}, got: {:?})",
+                        year,
+                        day,
+                        result.part,
+                        expected.as_deref().unwrap_or_default(),
+                        result.answer.as_deref().unwrap_or("not solved")
+                    );
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} skipped\n",
+        if failed == 0 { "ok" } else { "FAILED" },
+        passed,
+        failed,
+        skipped
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}